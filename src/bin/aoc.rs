@@ -0,0 +1,289 @@
+use std::{
+    path::{Path, PathBuf},
+    slice,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use utils::{
+    answers::{Answers, Verdict, ANSWERS_FILE},
+    bench::Stats,
+    days,
+    input::InputSource,
+    input_dir, logging, report,
+    runner::{self, Outcome, Status},
+    scaffold,
+    solver::{Day, Part},
+};
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2023 solutions")]
+struct Cli {
+    /// Directory to read inputs from, overriding $AOC_INPUT_DIR
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// Answers file to verify results against, defaults to answers.toml in the input directory
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+
+    /// Show more diagnostic output: -v for info, -vv for debug, -vvv for trace
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Log filter like "day5=trace,day7=debug", overriding $AOC_LOG
+    #[arg(long, global = true)]
+    log: Option<String>,
+
+    /// How to print the results of run and all
+    #[arg(long, global = true, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves one part of a day
+    Run {
+        day: u32,
+        part: Part,
+        /// Use the example input instead, optionally the numbered one (dayN_example_M.txt)
+        #[arg(long, num_args = 0..=1, default_missing_value = "0", conflicts_with = "file")]
+        example: Option<u32>,
+        /// Read the input from this file, or from stdin if it is "-"
+        #[arg(long)]
+        file: Option<InputSource>,
+    },
+    /// Times parsing and both parts, for one day or all of them
+    Bench {
+        /// Day to benchmark, all days if omitted
+        day: Option<u32>,
+        /// How often to run each step
+        #[arg(long, default_value_t = 10, value_parser = parse_runs)]
+        runs: usize,
+        /// Use the example inputs instead, optionally the numbered ones
+        #[arg(long, num_args = 0..=1, default_missing_value = "0")]
+        example: Option<u32>,
+    },
+    /// Solves every implemented part of every day and checks the answers.
+    /// Fails if any answer is wrong or any part fails or times out
+    All {
+        /// Use all example inputs of each day instead
+        #[arg(long)]
+        example: bool,
+        /// Solve the days on separate threads
+        #[arg(long)]
+        parallel: bool,
+        /// Days to leave out, e.g. "8,12"
+        #[arg(long, value_delimiter = ',')]
+        skip: Vec<u32>,
+        /// Seconds after which a part counts as failed, so slow days like
+        /// day 8 part 2 do not hold up the rest of the calendar
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Creates the module, binary, inputs and example test for a new day
+    New {
+        day: u32,
+        /// Project to add the day to, defaults to the one this binary was built from
+        #[arg(long)]
+        root: Option<PathBuf>,
+    },
+}
+
+fn parse_runs(s: &str) -> Result<usize, String> {
+    match s.parse() {
+        Ok(0) => Err("at least one run is needed".to_owned()),
+        Ok(runs) => Ok(runs),
+        Err(e) => Err(format!("{e}")),
+    }
+}
+
+fn input_source(day: u32, example: Option<u32>, file: Option<InputSource>) -> InputSource {
+    match (example, file) {
+        (_, Some(source)) => source,
+        (Some(0), None) => InputSource::named(format!("day{day}_example.txt")),
+        (Some(n), None) => InputSource::named(format!("day{day}_example_{n}.txt")),
+        (None, None) => InputSource::named(format!("day{day}.txt")),
+    }
+}
+
+fn format_stats(stats: Option<Stats>) -> String {
+    stats.map_or_else(|| "-".to_owned(), |stats| stats.to_string())
+}
+
+fn bench(dir: &Path, day: Option<u32>, runs: usize, example: Option<u32>) -> Result<()> {
+    let solutions: Vec<&Day> = match day {
+        Some(day) => vec![days::get(day).ok_or_else(|| anyhow!("Day {day} is not solved"))?],
+        None => days::DAYS.iter().collect(),
+    };
+
+    println!(
+        "{:>3}  {:<24}{:<24}{:<24}{:>10}",
+        "Day", "Parse (min / median)", "Part 1", "Part 2", "Total"
+    );
+
+    let mut total = Duration::ZERO;
+    for solution in solutions {
+        let result = input_source(solution.day, example, None)
+            .read_in(dir)
+            .and_then(|input| (solution.bench)(&input, runs));
+
+        match result {
+            Ok(benchmark) => {
+                total += benchmark.total();
+                println!(
+                    "{:>3}  {:<24}{:<24}{:<24}{:>10.2?}",
+                    solution.day,
+                    benchmark.parse.to_string(),
+                    format_stats(benchmark.part_one),
+                    format_stats(benchmark.part_two),
+                    benchmark.total()
+                );
+            }
+            Err(e) => println!("{:>3}  {e:#}", solution.day),
+        }
+    }
+
+    println!("{:>3}  {:<72}{:>10.2?}", "", "", total);
+
+    Ok(())
+}
+
+fn print_table(outcomes: &[Outcome]) {
+    println!(
+        "{:>3}  {:>4}  {:<12}{:<20}{:>10}  Status",
+        "Day", "Part", "Input", "Answer", "Time"
+    );
+    for outcome in outcomes {
+        println!(
+            "{:>3}  {:>4}  {:<12}{:<20}{:>10.2?}  {}",
+            outcome.day,
+            outcome
+                .part
+                .map_or_else(|| "-".to_owned(), |part| part.to_string()),
+            outcome.input,
+            outcome.answer().unwrap_or("-"),
+            outcome.duration,
+            outcome.status
+        );
+    }
+
+    let total: Duration = outcomes.iter().map(|outcome| outcome.duration).sum();
+    println!("{:>3}  {:>4}  {:<32}{:>10.2?}", "", "", "", total);
+}
+
+fn all(
+    dir: &Path,
+    answers: &Answers,
+    format: Format,
+    example: bool,
+    parallel: bool,
+    skip: &[u32],
+    timeout: Duration,
+) -> Result<()> {
+    let solutions: Vec<&Day> = days::DAYS
+        .iter()
+        .filter(|solution| !skip.contains(&solution.day))
+        .collect();
+
+    let outcomes = runner::solve_all(&solutions, dir, example, answers, parallel, Some(timeout));
+
+    match format {
+        Format::Table => print_table(&outcomes),
+        Format::Json => println!("{}", report::json(&outcomes)?),
+        Format::Csv => print!("{}", report::csv(&outcomes)?),
+    }
+
+    let wrong = outcomes.iter().filter(|outcome| outcome.is_wrong()).count();
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.is_failed())
+        .count();
+    if wrong > 0 || failed > 0 {
+        bail!("{wrong} answer(s) did not match the expected ones and {failed} part(s) failed");
+    }
+
+    Ok(())
+}
+
+fn new(root: &Path, day: u32) -> Result<()> {
+    for path in scaffold::scaffold(root, day)? {
+        println!("{}", path.display());
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    logging::init(cli.verbose, cli.log.as_deref());
+
+    let dir = cli.input_dir.unwrap_or_else(input_dir);
+    let answers = Answers::load(&cli.answers.unwrap_or_else(|| dir.join(ANSWERS_FILE)))?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            example,
+            file,
+        } => {
+            let solution = days::get(day).ok_or_else(|| anyhow!("Day {day} is not solved"))?;
+            let source = input_source(day, example, file);
+            let input = source.read_in(&dir)?;
+            let outcome = runner::solve_part(solution, &input, &source, part, &answers, None)
+                .ok_or_else(|| anyhow!("Day {day} part {part} is not implemented"))?;
+
+            match cli.format {
+                Format::Table => {
+                    if let Status::Solved { answer, verdict } = &outcome.status {
+                        println!("{answer} ({verdict})");
+                    }
+                }
+                Format::Json => println!("{}", report::json(slice::from_ref(&outcome))?),
+                Format::Csv => print!("{}", report::csv(slice::from_ref(&outcome))?),
+            }
+
+            match &outcome.status {
+                Status::Failed(error) => bail!("{error}"),
+                Status::Solved {
+                    verdict: Verdict::Wrong { expected },
+                    ..
+                } => bail!("Day {day} part {part} did not match the expected answer {expected}"),
+                Status::Solved { .. } => {}
+            }
+        }
+        Command::Bench { day, runs, example } => bench(&dir, day, runs, example)?,
+        Command::All {
+            example,
+            parallel,
+            skip,
+            timeout,
+        } => all(
+            &dir,
+            &answers,
+            cli.format,
+            example,
+            parallel,
+            &skip,
+            Duration::from_secs(timeout),
+        )?,
+        Command::New { day, root } => new(
+            &root.unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR"))),
+            day,
+        )?,
+    }
+
+    Ok(())
+}
@@ -1,45 +1,34 @@
-use std::collections::BTreeMap;
+use std::env;
+
+use anyhow::{bail, Result};
+use utils::{
+    days::day1::{Day1, DigitMatcher},
+    input::InputSource,
+    logging,
+    solver::{run, Part},
+};
+
+/// Streams a calibration document of any size through one part, reading
+/// the lines one at a time.
+fn stream(part: Part, source: InputSource) -> Result<()> {
+    logging::init(0, None);
+
+    let matcher = match part {
+        Part::One => DigitMatcher::digits(),
+        Part::Two => DigitMatcher::english(),
+    };
+    println!("Part {part}: {}", matcher.calibrate_reader(source.open()?)?);
 
-use anyhow::Result;
-use utils::get_lines;
+    Ok(())
+}
 
+/// Solves both parts for `day1.txt`, or with `day1 <part> <file>` streams the
+/// file, or stdin for `-`.
 fn main() -> Result<()> {
-    let lines = get_lines("day1.txt").unwrap();
-    dbg!(&lines);
-
-    let mut sum = 0;
-    for l in &lines {
-        dbg!(l);
-        let numbers = 1..=9;
-        let strings = vec![
-            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
-        ];
-
-        let mut matches = BTreeMap::new();
-        for (i, s) in numbers.zip(strings) {
-            if let Some(index) = l.find(&i.to_string()) {
-                matches.insert(index, i);
-            }
-
-            if let Some(index) = l.find(s) {
-                matches.insert(index, i);
-            }
-
-            if let Some(index) = l.rfind(&i.to_string()) {
-                matches.insert(index, i);
-            }
-
-            if let Some(index) = l.rfind(&s) {
-                matches.insert(index, i);
-            }
-        }
-
-        dbg!(&matches);
-
-        sum += 10 * matches.first_key_value().unwrap().1 + matches.last_key_value().unwrap().1;
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.as_slice() {
+        [] => run::<Day1>("day1.txt"),
+        [part, file] => stream(part.parse()?, file.parse()?),
+        _ => bail!("Usage: day1 [<part> <file or ->]"),
     }
-
-    println!("{}", sum);
-
-    Ok(())
 }
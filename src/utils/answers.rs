@@ -0,0 +1,198 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    fs,
+    path::Path,
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use toml::{Table, Value};
+
+use crate::{input::InputSource, solver::Part};
+
+/// Name of the answers file inside the input directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { expected } => write!(f, "wrong, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Known answers, keyed by day, input and part. The file looks like
+///
+/// ```toml
+/// [day2.example]
+/// part1 = 8
+/// part2 = 2286
+///
+/// [day2.input]
+/// part2 = 69929
+/// ```
+///
+/// where the input name is the file name without the `dayN_` prefix and
+/// extension, see [`input_key`].
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, String, Part), String>,
+}
+
+impl Answers {
+    /// Loads the answers file, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Could not read answers from {}", path.display()))?;
+        Answers::parse(&text).with_context(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: Table = text.parse()?;
+        let mut answers = HashMap::new();
+
+        for (day_key, inputs) in table {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| anyhow!("Expected a table named dayN, got {day_key}"))?;
+            let inputs = as_table(&inputs, &day_key)?;
+
+            for (input, parts) in inputs {
+                for (part_key, value) in as_table(parts, &format!("{day_key}.{input}"))? {
+                    let part = match part_key.as_str() {
+                        "part1" => Part::One,
+                        "part2" => Part::Two,
+                        _ => bail!("Expected part1 or part2 in {day_key}.{input}, got {part_key}"),
+                    };
+                    let answer = match value {
+                        Value::String(s) => s.clone(),
+                        Value::Integer(i) => i.to_string(),
+                        _ => bail!(
+                            "Answer {day_key}.{input}.{part_key} must be a string or an integer"
+                        ),
+                    };
+                    answers.insert((day, input.clone(), part), answer);
+                }
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn expected(&self, day: u32, input: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(day, input.to_owned(), part))
+            .map(|s| s.as_str())
+    }
+
+    /// Whether any answer is recorded for this input of `day`.
+    pub fn has_input(&self, day: u32, input: &str) -> bool {
+        [Part::One, Part::Two]
+            .into_iter()
+            .any(|part| self.expected(day, input, part).is_some())
+    }
+
+    pub fn check(&self, day: u32, input: &str, part: Part, answer: &str) -> Verdict {
+        match self.expected(day, input, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+fn as_table<'a>(value: &'a Value, name: &str) -> Result<&'a Table> {
+    value
+        .as_table()
+        .ok_or_else(|| anyhow!("Expected {name} to be a table"))
+}
+
+/// The name answers for an input are recorded under: `input` for `dayN.txt`,
+/// `example` for `dayN_example.txt` and `example_2` for `dayN_example_2.txt`.
+/// Inputs outside the input directory have no name.
+pub fn input_key(day: u32, source: &InputSource) -> Option<String> {
+    let InputSource::Named(name) = source else {
+        return None;
+    };
+
+    let stem = name.strip_suffix(".txt")?;
+    let prefix = format!("day{day}");
+    if stem == prefix {
+        Some("input".to_owned())
+    } else {
+        stem.strip_prefix(&prefix)?
+            .strip_prefix('_')
+            .map(|s| s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_inputs() {
+        let key = |day, name: &str| input_key(day, &InputSource::named(name));
+        assert_eq!(key(1, "day1.txt").as_deref(), Some("input"));
+        assert_eq!(key(1, "day1_example.txt").as_deref(), Some("example"));
+        assert_eq!(key(1, "day1_example_2.txt").as_deref(), Some("example_2"));
+        assert_eq!(key(1, "day10.txt"), None);
+        assert_eq!(key(1, "day1.in"), None);
+        assert_eq!(input_key(1, &InputSource::text("")), None);
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("[day2.example]\npart1 = 8\npart2 = \"2286\"\n").unwrap();
+        assert_eq!(
+            answers.check(2, "example", Part::One, "8"),
+            Verdict::Correct
+        );
+        assert_eq!(
+            answers.check(2, "example", Part::Two, "1"),
+            Verdict::Wrong {
+                expected: "2286".to_owned()
+            }
+        );
+        assert_eq!(answers.check(2, "input", Part::One, "8"), Verdict::Unknown);
+        assert!(answers.has_input(2, "example"));
+        assert!(!answers.has_input(3, "example"));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        let error = |text| format!("{:#}", Answers::parse(text).unwrap_err());
+        assert_eq!(
+            error("[dayX.input]\npart1 = 1"),
+            "Expected a table named dayN, got dayX"
+        );
+        assert_eq!(
+            error("[day1.input]\npart3 = 1"),
+            "Expected part1 or part2 in day1.input, got part3"
+        );
+        assert_eq!(
+            error("[day1.input]\npart1 = 1.5"),
+            "Answer day1.input.part1 must be a string or an integer"
+        );
+        assert_eq!(
+            error("[day1]\ninput = 1"),
+            "Expected day1.input to be a table"
+        );
+    }
+}
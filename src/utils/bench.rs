@@ -0,0 +1,137 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, ensure, Result};
+
+use crate::solver::{is_not_implemented, Solver};
+
+/// Timings of repeated runs of one step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2?} / {:.2?}", self.min, self.median)
+    }
+}
+
+/// Timings for parsing and both parts of a day. Parts that are not
+/// implemented have no timings.
+#[derive(Debug, Clone, Copy)]
+pub struct Benchmark {
+    pub parse: Stats,
+    pub part_one: Option<Stats>,
+    pub part_two: Option<Stats>,
+}
+
+impl Benchmark {
+    pub fn total(&self) -> Duration {
+        [Some(self.parse), self.part_one, self.part_two]
+            .into_iter()
+            .flatten()
+            .map(|stats| stats.median)
+            .sum()
+    }
+}
+
+fn time<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<Option<Stats>> {
+    let mut samples = Vec::with_capacity(runs);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        match result {
+            Ok(_) => samples.push(elapsed),
+            Err(e) if is_not_implemented(&e) => return Ok(None),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(Some(Stats::from_samples(samples)))
+}
+
+/// Times parsing and both parts separately, running each `runs` times.
+pub fn bench<S: Solver>(input: &str, runs: usize) -> Result<Benchmark> {
+    ensure!(runs > 0, "At least one run is needed");
+
+    let parse =
+        time(runs, || S::parse(input))?.ok_or_else(|| anyhow!("Parsing is not implemented"))?;
+    let parsed = S::parse(input)?;
+
+    Ok(Benchmark {
+        parse,
+        part_one: time(runs, || S::part_one(&parsed))?,
+        part_two: time(runs, || S::part_two(&parsed))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::not_implemented;
+
+    struct Halfway;
+
+    impl Solver for Halfway {
+        const DAY: u32 = 0;
+
+        type Input = usize;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self::Input> {
+            Ok(input.len())
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::Answer1> {
+            Ok(input * 2)
+        }
+
+        fn part_two(_input: &Self::Input) -> Result<Self::Answer2> {
+            not_implemented()
+        }
+    }
+
+    #[test]
+    fn takes_min_and_median() {
+        let stats = Stats::from_samples(
+            [5, 1, 4, 2, 3]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+        assert_eq!(
+            stats,
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+            }
+        );
+    }
+
+    #[test]
+    fn skips_parts_that_are_not_implemented() {
+        let benchmark = bench::<Halfway>("abc", 3).unwrap();
+        assert!(benchmark.part_one.is_some());
+        assert!(benchmark.part_two.is_none());
+
+        assert!(bench::<Halfway>("abc", 0).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use std::io::BufRead;
+
+use anyhow::{anyhow, Context, Result};
+use log::{debug, trace};
+
+use crate::solver::Solver;
+
+const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Finds the digits of a calibration line: the digits `1` to `9`, and
+/// optionally digits spelled out as words from a table.
+#[derive(Debug, Clone, Default)]
+pub struct DigitMatcher {
+    words: Vec<(String, u32)>,
+}
+
+impl DigitMatcher {
+    /// Only matches digits.
+    pub fn digits() -> Self {
+        DigitMatcher::default()
+    }
+
+    /// Matches digits and the English words `one` to `nine`.
+    pub fn english() -> Self {
+        DigitMatcher::with_words(ENGLISH)
+    }
+
+    /// Matches digits and the given words, e.g. `[("eins", 1), ("zwei", 2)]`.
+    pub fn with_words(words: impl IntoIterator<Item = (impl Into<String>, u32)>) -> Self {
+        DigitMatcher {
+            words: words
+                .into_iter()
+                .map(|(word, digit)| (word.into(), digit))
+                .collect(),
+        }
+    }
+
+    fn digit_at(&self, rest: &str) -> Option<u32> {
+        let c = rest.chars().next()?;
+        if let Some(digit) = c.to_digit(10).filter(|d| *d != 0) {
+            return Some(digit);
+        }
+
+        self.words
+            .iter()
+            .find(|(word, _)| rest.starts_with(word.as_str()))
+            .map(|(_, digit)| *digit)
+    }
+
+    /// The first and the last digit of `line`, found in a single pass. Words
+    /// may overlap, so `eightwo` ends in a two.
+    pub fn first_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut first = None;
+        let mut last = None;
+
+        for (i, _) in line.char_indices() {
+            if let Some(digit) = self.digit_at(&line[i..]) {
+                first.get_or_insert(digit);
+                last = Some(digit);
+            }
+        }
+
+        Some((first?, last?))
+    }
+
+    /// The number made of the first and the last digit of `line`.
+    pub fn calibration_value(&self, line: &str) -> Result<u32> {
+        let (first, last) = self
+            .first_last(line)
+            .ok_or_else(|| anyhow!("No digit in line {line:?}"))?;
+        debug!("{line}: {first} {last}");
+        Ok(10 * first + last)
+    }
+
+    /// The sum of the calibration values of all lines.
+    pub fn calibrate<'a>(&self, lines: impl IntoIterator<Item = &'a str>) -> Result<u32> {
+        lines
+            .into_iter()
+            .map(|line| self.calibration_value(line))
+            .sum()
+    }
+
+    /// Like `calibrate`, but reads the lines one at a time, so the memory
+    /// needed does not grow with the input.
+    pub fn calibrate_reader(&self, mut reader: impl BufRead) -> Result<u64> {
+        let mut line = String::new();
+        let mut sum = 0;
+
+        for number in 1.. {
+            line.clear();
+            if reader.read_line(&mut line)? == 0 {
+                break;
+            }
+
+            let value = self
+                .calibration_value(line.trim_end_matches(['\r', '\n']))
+                .with_context(|| format!("Line {number}"))?;
+            sum += u64::from(value);
+        }
+
+        Ok(sum)
+    }
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u32 = 1;
+
+    type Input = Vec<String>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+        trace!("{:?}", lines);
+        Ok(lines)
+    }
+
+    fn part_one(lines: &Self::Input) -> Result<Self::Answer1> {
+        DigitMatcher::digits().calibrate(lines.iter().map(|l| l.as_str()))
+    }
+
+    fn part_two(lines: &Self::Input) -> Result<Self::Answer2> {
+        DigitMatcher::english().calibrate(lines.iter().map(|l| l.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlapping_words() {
+        let matcher = DigitMatcher::english();
+        assert_eq!(matcher.first_last("eightwo"), Some((8, 2)));
+        assert_eq!(matcher.first_last("xtwone3four"), Some((2, 4)));
+        assert_eq!(matcher.first_last("oneight"), Some((1, 8)));
+        assert_eq!(matcher.first_last("7"), Some((7, 7)));
+        assert_eq!(matcher.first_last("zero"), None);
+
+        assert_eq!(DigitMatcher::digits().first_last("eightwo"), None);
+    }
+
+    #[test]
+    fn custom_words() {
+        let matcher = DigitMatcher::with_words([("eins", 1), ("zwei", 2), ("drei", 3)]);
+        assert_eq!(matcher.calibration_value("zweieins").unwrap(), 21);
+        assert_eq!(matcher.calibration_value("xdrei7one").unwrap(), 37);
+        assert!(matcher.calibration_value("onetwo").is_err());
+    }
+
+    #[test]
+    fn streams_lines() {
+        let input = "two1nine\r\neightwothree\nabcone2threexyz";
+        assert_eq!(
+            DigitMatcher::english()
+                .calibrate_reader(input.as_bytes())
+                .unwrap(),
+            29 + 83 + 13
+        );
+
+        let error = DigitMatcher::digits()
+            .calibrate_reader(input.as_bytes())
+            .unwrap_err();
+        assert_eq!(
+            format!("{error:#}"),
+            "Line 2: No digit in line \"eightwothree\""
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use log::{debug, trace};
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solver::{not_implemented, Solver},
+};
+
+type NodeId = Point;
+
+/// The neighbours a pipe points at. Only pipes pointing at each other are
+/// connected, see `connect_nodes`.
+fn pipe_ends(node: NodeId, pipe: char) -> Result<Option<[NodeId; 2]>> {
+    let directions = match pipe {
+        '.' | 'S' => return Ok(None),
+        '|' => [Direction::North, Direction::South],
+        '-' => [Direction::East, Direction::West],
+        'L' => [Direction::North, Direction::East],
+        'J' => [Direction::North, Direction::West],
+        '7' => [Direction::South, Direction::West],
+        'F' => [Direction::South, Direction::East],
+        default => bail!("Unexpected character {} at {}", default, node),
+    };
+
+    Ok(Some(directions.map(|direction| node.step(direction))))
+}
+
+/// Connects the cells whose pipes point at each other, and the start to
+/// every pipe pointing at it.
+fn connect_nodes(
+    ends: &HashMap<NodeId, [NodeId; 2]>,
+    start: NodeId,
+) -> HashMap<NodeId, Vec<NodeId>> {
+    let mut graph: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
+
+    for (node, targets) in ends {
+        for target in targets {
+            let points_back =
+                target == &start || ends.get(target).is_some_and(|back| back.contains(node));
+            if points_back {
+                graph.entry(*node).or_default().push(*target);
+                if target == &start {
+                    graph.entry(start).or_default().push(*node);
+                }
+            }
+        }
+    }
+
+    graph
+}
+
+/// Follows the pipes from the start until it is reached again.
+fn walk_loop(graph: &HashMap<NodeId, Vec<NodeId>>, start: NodeId) -> Result<Vec<NodeId>> {
+    let mut neighbours = graph.get(&start).into_iter().flatten();
+    let first = neighbours
+        .next()
+        .ok_or_else(|| anyhow!("No pipe connects to the start"))?;
+
+    let mut path = vec![start];
+    let mut previous = start;
+    let mut current = *first;
+
+    while current != start {
+        path.push(current);
+        let next = graph
+            .get(&current)
+            .into_iter()
+            .flatten()
+            .find(|next| **next != previous)
+            .ok_or_else(|| anyhow!("The loop through the start ends at {}", current))?;
+        previous = current;
+        current = *next;
+    }
+
+    Ok(path)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let grid = Grid::from_chars(input)?;
+
+        trace!("\n{}", grid);
+
+        Ok(grid)
+    }
+
+    fn part_one(grid: &Self::Input) -> Result<Self::Answer1> {
+        let mut ends = HashMap::new();
+        let mut start = None;
+
+        for (position, char) in grid.cells() {
+            let node = Point::from(position);
+            if *char == 'S' {
+                start = Some(node);
+            }
+            if let Some(targets) = pipe_ends(node, *char)? {
+                ends.insert(node, targets);
+            }
+        }
+
+        let start = start.ok_or_else(|| anyhow!("No start in the grid"))?;
+        let graph = connect_nodes(&ends, start);
+        let path = walk_loop(&graph, start)?;
+
+        for node in &path {
+            trace!("-> {} {:?}", node, grid.get_point(*node));
+        }
+        debug!("Path length {}", path.len());
+
+        Ok(path.len() / 2)
+    }
+
+    fn part_two(_grid: &Self::Input) -> Result<Self::Answer2> {
+        not_implemented()
+    }
+}
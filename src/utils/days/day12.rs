@@ -0,0 +1,77 @@
+use anyhow::Result;
+use log::trace;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, space1},
+    combinator::value,
+    multi::{many1, separated_list1},
+    IResult,
+};
+
+use crate::{
+    parse::{number, parse_lines},
+    solver::{not_implemented, Solver},
+};
+
+#[derive(Debug, Clone)]
+pub enum SpringType {
+    Unknown,
+    Working,
+    Broken,
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    pub positions: Vec<SpringType>,
+    pub wanted_groups: Vec<usize>,
+    pub current_groups: Vec<(usize, usize)>,
+}
+
+fn parse_spring(input: &str) -> IResult<&str, SpringType> {
+    alt((
+        value(SpringType::Working, char('.')),
+        value(SpringType::Broken, char('#')),
+        value(SpringType::Unknown, char('?')),
+    ))(input)
+}
+
+fn parse_line(line: &str) -> IResult<&str, Problem> {
+    let (input, positions) = many1(parse_spring)(line)?;
+    let (input, _) = space1(input)?;
+    let (input, damaged_groups) = separated_list1(tag(","), number)(input)?;
+
+    Ok((
+        input,
+        Problem {
+            positions,
+            wanted_groups: damaged_groups,
+            current_groups: vec![],
+        },
+    ))
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<Problem>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let problems = parse_lines(input, parse_line)?;
+        trace!("{:?}", problems);
+
+        Ok(problems)
+    }
+
+    fn part_one(_problems: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(_problems: &Self::Input) -> Result<Self::Answer2> {
+        not_implemented()
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    collections::{
+        hash_map::Entry::{Occupied, Vacant},
+        HashMap,
+    },
+    fmt::{self, Display},
+};
+
+use anyhow::{anyhow, Result};
+use log::{debug, trace};
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solver::{not_implemented, Solver},
+};
+
+const CYCLES: usize = 1_000_000_000;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Game {
+    grid: Grid<char>,
+}
+
+impl Game {
+    fn move_tile(&mut self, row: usize, column: usize, direction: Direction) -> bool {
+        let start = Point::from((column, row));
+        if self.grid[start] != 'O' {
+            return false;
+        }
+
+        let mut position = start;
+        while self.grid.get_point(position.step(direction)) == Some(&'.') {
+            position = position.step(direction);
+        }
+
+        self.grid[start] = '.';
+        self.grid[position] = 'O';
+
+        position != start
+    }
+
+    fn step(&mut self, direction: Direction) -> bool {
+        let mut moved = false;
+
+        match direction {
+            Direction::North => {
+                for current_row in 0..self.grid.height() {
+                    for current_column in 0..self.grid.width() {
+                        if self.grid[(current_column, current_row)] == 'O'
+                            && self.move_tile(current_row, current_column, direction)
+                        {
+                            moved = true;
+                        }
+                    }
+                }
+            }
+            Direction::East => {
+                for current_row in 0..self.grid.height() {
+                    for current_column in (0..self.grid.width()).rev() {
+                        if self.grid[(current_column, current_row)] == 'O'
+                            && self.move_tile(current_row, current_column, direction)
+                        {
+                            moved = true;
+                        }
+                    }
+                }
+            }
+            Direction::South => {
+                for current_row in (0..self.grid.height()).rev() {
+                    for current_column in 0..self.grid.width() {
+                        if self.grid[(current_column, current_row)] == 'O'
+                            && self.move_tile(current_row, current_column, direction)
+                        {
+                            moved = true;
+                        }
+                    }
+                }
+            }
+            Direction::West => {
+                for current_row in 0..self.grid.height() {
+                    for current_column in 0..self.grid.width() {
+                        if self.grid[(current_column, current_row)] == 'O'
+                            && self.move_tile(current_row, current_column, direction)
+                        {
+                            moved = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        trace!("After moving in direction {:?}:\n{}", direction, self);
+
+        moved
+    }
+
+    fn cycle(&mut self) {
+        self.step(Direction::North);
+        self.step(Direction::West);
+        self.step(Direction::South);
+        self.step(Direction::East);
+    }
+
+    fn count_weight(&self) -> usize {
+        self.grid
+            .cells()
+            .filter_map(|((_, row), tile)| {
+                if *tile != 'O' {
+                    None
+                } else {
+                    Some(self.grid.height() - row)
+                }
+            })
+            .sum()
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Game;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Game {
+            grid: Grid::parse(input, |c| match c {
+                'O' | '#' | '.' => Ok(c),
+                _ => Err(anyhow!("Unexpected tile {c}")),
+            })?,
+        })
+    }
+
+    fn part_one(_game: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(game: &Self::Input) -> Result<Self::Answer2> {
+        let mut game = game.clone();
+        let mut seen = HashMap::new();
+
+        let mut i = 0;
+        while i < CYCLES {
+            game.cycle();
+
+            let entry = seen.entry(game.clone());
+            match entry {
+                Occupied(entry) => {
+                    debug!(
+                        "Already seen the game from iteration {} at iteration {}",
+                        i,
+                        entry.get()
+                    );
+
+                    // The cycles repeat from here on, so only the remainder is left to simulate.
+                    let period = i - entry.get();
+                    let remaining = (CYCLES - 1 - i) % period;
+                    for _ in 0..remaining {
+                        game.cycle();
+                    }
+                    break;
+                }
+                Vacant(entry) => {
+                    entry.insert(i);
+                }
+            }
+
+            trace!("After {} cycles:\n{}", i + 1, game);
+
+            i += 1;
+        }
+
+        debug!("Final state:\n{}", game);
+        Ok(game.count_weight())
+    }
+}
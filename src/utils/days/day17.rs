@@ -0,0 +1,123 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+};
+
+use anyhow::{anyhow, Result};
+use log::{debug, trace};
+
+use crate::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    solver::{not_implemented, Solver},
+};
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let graph = Grid::parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| anyhow!("Expected a digit, got {c}"))
+        })?;
+        trace!("\n{}", graph);
+        Ok(graph)
+    }
+
+    fn part_one(graph: &Self::Input) -> Result<Self::Answer1> {
+        let start_node = Point::ORIGIN;
+        let end_node = Point::new(graph.width() as i64 - 1, graph.height() as i64 - 1);
+
+        // A node is only settled for one direction and number of straight
+        // steps, since those decide where the crucible may go next.
+        let mut distances = HashMap::new();
+        let mut priority_queue = BinaryHeap::new();
+        let mut previous = HashMap::new();
+        let mut result = None;
+
+        // Initialize
+
+        for state in [
+            (start_node, Direction::East, 0),
+            (start_node, Direction::South, 0),
+        ] {
+            distances.insert(state, 0);
+            priority_queue.push((Reverse(0), state));
+        }
+
+        while let Some((Reverse(distance), state)) = priority_queue.pop() {
+            let (node, direction, steps) = state;
+
+            if distance > *distances.get(&state).unwrap_or(&u32::MAX) {
+                continue;
+            }
+
+            let weight = *graph
+                .get_point(node)
+                .ok_or_else(|| anyhow!("{} is outside of the map", node))?;
+
+            trace!(
+                "Visiting node {} '{}' with distance {} going {:?} for {} steps",
+                node,
+                weight,
+                distance,
+                direction,
+                steps
+            );
+
+            if end_node == node {
+                debug!("End reached with {}!", distance);
+                result = Some((distance, state));
+                break;
+            }
+
+            let mut update = |direction: Direction, steps| {
+                let neighbor_node = node.step(direction);
+                if let Some(&neighbor_weight) = graph.get_point(neighbor_node) {
+                    let neighbor = (neighbor_node, direction, steps);
+                    let current_neighbor_distance = *distances.get(&neighbor).unwrap_or(&u32::MAX);
+
+                    let new_distance = distance + neighbor_weight;
+
+                    if new_distance < current_neighbor_distance {
+                        distances.insert(neighbor, new_distance);
+                        previous.insert(neighbor, state);
+                        priority_queue.push((Reverse(new_distance), neighbor));
+                    }
+                }
+            };
+
+            update(direction.turn_left(), 1);
+            update(direction.turn_right(), 1);
+
+            if steps < 3 {
+                update(direction, steps + 1);
+            }
+
+            trace!("-> updated queue {:?}", priority_queue);
+        }
+
+        let (distance, end_state) = result.ok_or_else(|| anyhow!("End not reachable"))?;
+
+        let mut cur = end_state;
+        while cur.0 != start_node {
+            let reached_from = previous
+                .get(&cur)
+                .ok_or_else(|| anyhow!("No path back from {}", cur.0))?;
+            debug!("{} '{:?}'", cur.0, graph.get_point(cur.0));
+            cur = *reached_from;
+        }
+
+        Ok(distance)
+    }
+
+    fn part_two(_graph: &Self::Input) -> Result<Self::Answer2> {
+        not_implemented()
+    }
+}
@@ -0,0 +1,376 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{self, Display},
+};
+
+use anyhow::Result;
+use itertools::Itertools;
+use log::trace;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, space0},
+    combinator::map,
+    multi::separated_list0,
+    sequence::{pair, separated_pair, terminated},
+    IResult,
+};
+
+use crate::{
+    parse::{header, number, parse_lines},
+    solver::Solver,
+};
+
+/// The colour of a cube, stored in lower case.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Color(String);
+
+impl Color {
+    pub fn new(name: &str) -> Self {
+        Color(name.to_lowercase())
+    }
+
+    pub fn red() -> Self {
+        Color::new("red")
+    }
+
+    pub fn green() -> Self {
+        Color::new("green")
+    }
+
+    pub fn blue() -> Self {
+        Color::new("blue")
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A game mentions a colour that is not in the palette it was checked
+/// against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownColor {
+    pub game: u32,
+    pub color: Color,
+}
+
+impl Display for UnknownColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Game {} has unknown color {}", self.game, self.color)
+    }
+}
+
+impl std::error::Error for UnknownColor {}
+
+/// The colours cubes may have.
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: HashSet<Color>,
+}
+
+impl Palette {
+    pub fn new<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        Palette {
+            colors: names.into_iter().map(Color::new).collect(),
+        }
+    }
+
+    /// Red, green and blue, the colours of the puzzle.
+    pub fn standard() -> Self {
+        Palette::new(["red", "green", "blue"])
+    }
+
+    pub fn contains(&self, color: &Color) -> bool {
+        self.colors.contains(color)
+    }
+
+    pub fn colors(&self) -> impl Iterator<Item = &Color> {
+        self.colors.iter()
+    }
+
+    /// Fails on the first colour of `game` that is not in the palette.
+    pub fn check(&self, game: &Game) -> Result<(), UnknownColor> {
+        match game.draws().find(|draw| !self.contains(&draw.color)) {
+            Some(draw) => Err(UnknownColor {
+                game: game.game_number,
+                color: draw.color.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct CountColorPair {
+    color: Color,
+    count: u32,
+}
+
+impl CountColorPair {
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    game_number: u32,
+    sequences: Vec<Vec<CountColorPair>>,
+}
+
+impl Game {
+    pub fn number(&self) -> u32 {
+        self.game_number
+    }
+
+    /// The rounds of the game, each a list of the cubes shown at once.
+    pub fn rounds(&self) -> &[Vec<CountColorPair>] {
+        &self.sequences
+    }
+
+    /// Every handful of cubes shown in the game.
+    pub fn draws(&self) -> impl Iterator<Item = &CountColorPair> {
+        self.sequences.iter().flatten()
+    }
+
+    /// The fewest cubes of each colour the game could have been played with.
+    pub fn minimum_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        for draw in self.draws() {
+            let count = bag.cubes.entry(draw.color.clone()).or_default();
+            *count = (*count).max(draw.count);
+        }
+        bag
+    }
+
+    /// How many cubes of each colour were shown over all rounds.
+    pub fn total_draws(&self) -> Bag {
+        let mut bag = Bag::default();
+        for draw in self.draws() {
+            *bag.cubes.entry(draw.color.clone()).or_default() += draw.count;
+        }
+        bag
+    }
+
+    /// Whether the game could have been played with the cubes in `bag`.
+    pub fn is_possible_with(&self, bag: &Bag) -> bool {
+        self.draws()
+            .all(|draw| draw.count <= bag.count(&draw.color))
+    }
+}
+
+/// How many cubes of each colour are in the bag. Colours that are not
+/// mentioned have no cubes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bag {
+    cubes: HashMap<Color, u32>,
+}
+
+impl Bag {
+    pub fn new<'a>(cubes: impl IntoIterator<Item = (&'a str, u32)>) -> Self {
+        Bag {
+            cubes: cubes
+                .into_iter()
+                .map(|(name, count)| (Color::new(name), count))
+                .collect(),
+        }
+    }
+
+    pub fn count(&self, color: &Color) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    /// The colours with at least one cube and their counts, by colour.
+    pub fn iter(&self) -> impl Iterator<Item = (&Color, u32)> {
+        self.cubes
+            .iter()
+            .filter(|(_, count)| **count > 0)
+            .map(|(color, count)| (color, *count))
+            .sorted()
+    }
+
+    /// The product of the counts of all colours in `palette`, which is zero
+    /// if any of them is missing.
+    pub fn power(&self, palette: &Palette) -> u32 {
+        palette.colors().map(|color| self.count(color)).product()
+    }
+}
+
+/// The games for which `predicate` holds.
+pub fn filter_games<'a>(
+    games: &'a [Game],
+    predicate: impl Fn(&Game) -> bool + 'a,
+) -> impl Iterator<Item = &'a Game> {
+    games.iter().filter(move |game| predicate(game))
+}
+
+/// The games that could have been played with the cubes in `bag`.
+pub fn possible_games<'a>(games: &'a [Game], bag: &'a Bag) -> impl Iterator<Item = &'a Game> {
+    filter_games(games, |game| game.is_possible_with(bag))
+}
+
+/// How many cubes of each colour were shown over all games.
+pub fn total_draws(games: &[Game]) -> Bag {
+    let mut bag = Bag::default();
+    for game in games {
+        for (color, count) in game.total_draws().iter() {
+            *bag.cubes.entry(color.clone()).or_default() += count;
+        }
+    }
+    bag
+}
+
+/// How many games there are for each value of `key`, e.g. of the most red
+/// cubes shown at once.
+pub fn histogram<K: Ord>(games: &[Game], key: impl Fn(&Game) -> K) -> BTreeMap<K, usize> {
+    let mut histogram = BTreeMap::new();
+    for game in games {
+        *histogram.entry(key(game)).or_default() += 1;
+    }
+    histogram
+}
+
+fn parse_color(input: &str) -> IResult<&str, Color> {
+    map(alpha1, Color::new)(input)
+}
+
+fn parse_count_color_pair(input: &str) -> IResult<&str, CountColorPair> {
+    map(
+        separated_pair(number, space0, parse_color),
+        |(count, color)| CountColorPair { count, color },
+    )(input)
+}
+
+fn parse_sequence(input: &str) -> IResult<&str, Vec<CountColorPair>> {
+    separated_list0(terminated(tag(","), space0), parse_count_color_pair)(input)
+}
+
+fn parse_game(input: &str) -> IResult<&str, Game> {
+    map(
+        pair(
+            header("Game"),
+            separated_list0(terminated(tag(";"), space0), parse_sequence),
+        ),
+        |(game_number, sequences)| Game {
+            game_number,
+            sequences,
+        },
+    )(input)
+}
+
+/// Parses a game record, rejecting colours that are not in `palette`.
+pub fn parse_games(input: &str, palette: &Palette) -> Result<Vec<Game>> {
+    let games = parse_lines(input, parse_game)?;
+    trace!("{:?}", games);
+
+    for game in &games {
+        palette.check(game)?;
+    }
+
+    Ok(games)
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_games(input, &Palette::standard())
+    }
+
+    fn part_one(games: &Self::Input) -> Result<Self::Answer1> {
+        let bag = Bag::new([("red", 12), ("green", 13), ("blue", 14)]);
+        Ok(possible_games(games, &bag)
+            .map(|game| game.game_number)
+            .sum())
+    }
+
+    fn part_two(games: &Self::Input) -> Result<Self::Answer2> {
+        let palette = Palette::standard();
+        Ok(games
+            .iter()
+            .map(|game| game.minimum_bag().power(&palette))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAMES: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+
+    #[test]
+    fn minimum_bag_and_totals() {
+        let games = Day2::parse(GAMES).unwrap();
+        let minimum = games[0].minimum_bag();
+        assert_eq!(minimum, Bag::new([("red", 4), ("green", 2), ("blue", 6)]));
+        assert_eq!(minimum.power(&Palette::standard()), 48);
+        assert_eq!(Bag::new([("red", 4)]).power(&Palette::standard()), 0);
+
+        assert_eq!(
+            games[1].total_draws(),
+            Bag::new([("red", 1), ("green", 6), ("blue", 6)])
+        );
+        assert_eq!(total_draws(&games).count(&Color::red()), 31);
+    }
+
+    #[test]
+    fn queries() {
+        let games = Day2::parse(GAMES).unwrap();
+        let numbers = |games: Vec<&Game>| games.iter().map(|g| g.number()).collect::<Vec<_>>();
+
+        let bag = Bag::new([("red", 12), ("green", 13), ("blue", 14)]);
+        assert_eq!(numbers(possible_games(&games, &bag).collect()), vec![1, 2]);
+        assert_eq!(
+            numbers(filter_games(&games, |g| g.rounds().len() == 3).collect()),
+            vec![1, 2, 3]
+        );
+
+        let most_green = histogram(&games, |g| g.minimum_bag().count(&Color::green()));
+        assert_eq!(most_green, BTreeMap::from([(2, 1), (3, 1), (13, 1)]));
+    }
+
+    #[test]
+    fn unknown_colors() {
+        let error = Day2::parse("Game 1: 3 blue\nGame 2: 3 bleu").unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<UnknownColor>(),
+            Some(&UnknownColor {
+                game: 2,
+                color: Color::new("bleu")
+            })
+        );
+    }
+
+    #[test]
+    fn custom_palette() {
+        let input = "Game 1: 3 yellow, 1 red; 2 yellow";
+        assert!(Day2::parse(input).is_err());
+
+        let palette = Palette::new(["red", "yellow"]);
+        let games = parse_games(input, &palette).unwrap();
+        assert_eq!(
+            games[0].minimum_bag(),
+            Bag::new([("yellow", 3), ("red", 1)])
+        );
+        assert_eq!(games[0].minimum_bag().power(&palette), 3);
+    }
+}
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use log::{debug, trace};
+
+use crate::{
+    geometry::Point,
+    grid::{Grid, NumberSpan},
+    solver::Solver,
+};
+
+fn is_symbol(c: char) -> bool {
+    c != '.' && !c.is_ascii_digit()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub kind: char,
+    pub position: Point,
+}
+
+/// The numbers and symbols of an engine schematic, indexed by cell and by
+/// which of them touch each other.
+#[derive(Debug, Clone)]
+pub struct Schematic {
+    numbers: Vec<NumberSpan>,
+    symbols: Vec<Symbol>,
+    /// Indices into `numbers` and `symbols` for every cell they cover.
+    number_cells: HashMap<Point, usize>,
+    symbol_cells: HashMap<Point, usize>,
+    /// For each number the symbols it touches, and the other way around.
+    symbols_of_number: Vec<Vec<usize>>,
+    numbers_of_symbol: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn new(grid: &Grid<char>) -> Result<Self> {
+        let numbers = grid.number_spans()?;
+        let symbols: Vec<Symbol> = grid
+            .cells()
+            .filter(|(_, c)| is_symbol(**c))
+            .map(|(position, &kind)| Symbol {
+                kind,
+                position: Point::from(position),
+            })
+            .collect();
+
+        let number_cells: HashMap<_, _> = numbers
+            .iter()
+            .enumerate()
+            .flat_map(|(i, span)| span.cells().map(move |cell| (cell, i)))
+            .collect();
+        let symbol_cells: HashMap<_, _> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| (symbol.position, i))
+            .collect();
+
+        let mut symbols_of_number = vec![vec![]; numbers.len()];
+        let mut numbers_of_symbol = vec![vec![]; symbols.len()];
+        for (n, span) in numbers.iter().enumerate() {
+            for cell in &span.neighbours {
+                if let Some(&s) = symbol_cells.get(cell) {
+                    symbols_of_number[n].push(s);
+                    numbers_of_symbol[s].push(n);
+                }
+            }
+        }
+
+        Ok(Schematic {
+            numbers,
+            symbols,
+            number_cells,
+            symbol_cells,
+            symbols_of_number,
+            numbers_of_symbol,
+        })
+    }
+
+    pub fn numbers(&self) -> &[NumberSpan] {
+        &self.numbers
+    }
+
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// The number covering `cell`, if any.
+    pub fn number_at(&self, cell: Point) -> Option<&NumberSpan> {
+        self.number_cells.get(&cell).map(|&i| &self.numbers[i])
+    }
+
+    pub fn symbol_at(&self, cell: Point) -> Option<&Symbol> {
+        self.symbol_cells.get(&cell).map(|&i| &self.symbols[i])
+    }
+
+    /// The numbers touching the symbol at `cell`, none if there is no symbol.
+    pub fn numbers_touching(&self, cell: Point) -> impl Iterator<Item = &NumberSpan> {
+        self.symbol_cells
+            .get(&cell)
+            .into_iter()
+            .flat_map(|&s| &self.numbers_of_symbol[s])
+            .map(|&n| &self.numbers[n])
+    }
+
+    /// The symbols touching the number covering `cell`, none if there is no
+    /// number.
+    pub fn symbols_touching(&self, cell: Point) -> impl Iterator<Item = &Symbol> {
+        self.number_cells
+            .get(&cell)
+            .into_iter()
+            .flat_map(|&n| &self.symbols_of_number[n])
+            .map(|&s| &self.symbols[s])
+    }
+
+    /// The numbers touching at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &NumberSpan> {
+        self.numbers
+            .iter()
+            .zip(&self.symbols_of_number)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(span, _)| span)
+    }
+
+    /// The numbers touching no symbol at all.
+    pub fn isolated_numbers(&self) -> impl Iterator<Item = &NumberSpan> {
+        self.numbers
+            .iter()
+            .zip(&self.symbols_of_number)
+            .filter(|(_, symbols)| symbols.is_empty())
+            .map(|(span, _)| span)
+    }
+
+    /// The `*` symbols touching exactly two numbers, with those numbers.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, u64, u64)> {
+        self.symbols
+            .iter()
+            .zip(&self.numbers_of_symbol)
+            .filter_map(|(symbol, numbers)| match numbers.as_slice() {
+                &[a, b] if symbol.kind == '*' => {
+                    Some((symbol, self.numbers[a].value, self.numbers[b].value))
+                }
+                _ => None,
+            })
+    }
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    const DAY: u32 = 3;
+
+    type Input = Schematic;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Schematic::new(&Grid::from_chars(input)?)
+    }
+
+    fn part_one(schematic: &Self::Input) -> Result<Self::Answer1> {
+        Ok(schematic
+            .part_numbers()
+            .inspect(|span| trace!("Part number {}", span.value))
+            .map(|span| span.value)
+            .sum())
+    }
+
+    fn part_two(schematic: &Self::Input) -> Result<Self::Answer2> {
+        debug!("{} gears", schematic.gears().count());
+
+        Ok(schematic.gears().map(|(_, a, b)| a * b).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMATIC: &str = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    fn schematic() -> Schematic {
+        Day3::parse(SCHEMATIC).unwrap()
+    }
+
+    fn values<'a>(spans: impl Iterator<Item = &'a NumberSpan>) -> Vec<u64> {
+        spans.map(|span| span.value).collect()
+    }
+
+    #[test]
+    fn looks_up_cells() {
+        let schematic = schematic();
+        assert_eq!(
+            schematic.number_at(Point::new(1, 0)).map(|n| n.value),
+            Some(467)
+        );
+        assert_eq!(schematic.number_at(Point::new(3, 0)), None);
+        assert_eq!(
+            schematic.symbol_at(Point::new(6, 3)).map(|s| s.kind),
+            Some('#')
+        );
+        assert_eq!(schematic.symbols().len(), 6);
+    }
+
+    #[test]
+    fn adjacency_both_ways() {
+        let schematic = schematic();
+        assert_eq!(
+            values(schematic.numbers_touching(Point::new(3, 1))),
+            vec![467, 35]
+        );
+        assert_eq!(
+            values(schematic.numbers_touching(Point::new(0, 0))),
+            Vec::<u64>::new()
+        );
+
+        let symbols: Vec<_> = schematic.symbols_touching(Point::new(8, 2)).collect();
+        assert_eq!(
+            symbols,
+            vec![&Symbol {
+                kind: '#',
+                position: Point::new(6, 3)
+            }]
+        );
+        assert_eq!(schematic.symbols_touching(Point::new(5, 0)).count(), 0);
+    }
+
+    #[test]
+    fn isolated_numbers_and_gears() {
+        let schematic = schematic();
+        assert_eq!(values(schematic.isolated_numbers()), vec![114, 58]);
+
+        let gears: Vec<_> = schematic
+            .gears()
+            .map(|(symbol, a, b)| (symbol.position, a, b))
+            .collect();
+        assert_eq!(
+            gears,
+            vec![(Point::new(3, 1), 467, 35), (Point::new(5, 8), 755, 598)]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use log::{debug, trace};
+use nom::{
+    bytes::complete::tag,
+    character::complete::space1,
+    sequence::{separated_pair, tuple},
+    IResult,
+};
+
+use crate::{
+    parse::{header, numbers, parse_lines},
+    solver::Solver,
+};
+
+#[derive(Debug)]
+pub struct Game {
+    numbers: Vec<u64>,
+    winning: Vec<u64>,
+}
+
+impl Game {
+    /// How many of the card's numbers are winning numbers.
+    pub fn count_winning(&self) -> usize {
+        let set: HashSet<u64> = HashSet::from_iter(self.winning.iter().copied());
+        self.numbers.iter().filter(|x| set.contains(x)).count()
+    }
+
+    /// One point for the first match, doubled for every further one.
+    pub fn points(&self) -> Result<u64> {
+        match self.count_winning() {
+            0 => Ok(0),
+            n => u32::try_from(n - 1)
+                .ok()
+                .and_then(|shift| 1u64.checked_shl(shift))
+                .ok_or_else(|| anyhow!("{n} matches are worth too many points")),
+        }
+    }
+}
+
+/// How many copies of each card there are in the end, given that a card
+/// with `n` matches wins a copy of each of the next `n` cards.
+pub fn copy_counts(cards: &[Game]) -> Result<Vec<u64>> {
+    let mut counts: Vec<u64> = vec![1; cards.len()];
+
+    for (i, g) in cards.iter().enumerate() {
+        let winning = g.count_winning();
+        debug!("Card {} has {} copies and {} wins", i, counts[i], winning);
+        for j in i + 1..(i + 1 + winning).min(counts.len()) {
+            counts[j] = counts[j]
+                .checked_add(counts[i])
+                .ok_or_else(|| anyhow!("Too many copies of card {} after card {}", j + 1, i + 1))?;
+        }
+    }
+
+    trace!("{:?}", counts);
+    Ok(counts)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Game> {
+    // Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+
+    let (input, _) = header::<u32>("Card")(input)?;
+    let (input, (numbers, winning)) =
+        separated_pair(numbers, tuple((space1, tag("|"), space1)), numbers)(input)?;
+
+    Ok((input, Game { numbers, winning }))
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Game>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let cards = parse_lines(input, parse_line)?;
+        trace!("{:?}", cards);
+
+        Ok(cards)
+    }
+
+    fn part_one(cards: &Self::Input) -> Result<Self::Answer1> {
+        cards.iter().enumerate().try_fold(0u64, |sum, (i, card)| {
+            sum.checked_add(card.points()?)
+                .ok_or_else(|| anyhow!("Too many points after card {}", i + 1))
+        })
+    }
+
+    fn part_two(cards: &Self::Input) -> Result<Self::Answer2> {
+        copy_counts(cards)?
+            .iter()
+            .try_fold(0u64, |sum, &count| sum.checked_add(count))
+            .ok_or_else(|| anyhow!("Too many cards in total"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_and_copies() {
+        let cards = Day4::parse(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+        )
+        .unwrap();
+
+        let points: Vec<u64> = cards.iter().map(|card| card.points().unwrap()).collect();
+        assert_eq!(points, vec![8, 2, 0]);
+        assert_eq!(copy_counts(&cards).unwrap(), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn rejects_overflowing_points() {
+        let ones = vec!["1"; 65].join(" ");
+        let cards = Day4::parse(&format!("Card 1: {ones} | 1")).unwrap();
+        assert!(cards[0].points().is_err());
+        assert!(Day4::part_one(&cards).is_err());
+
+        let cards = Day4::parse(&format!("Card 1: {} | 1", vec!["1"; 64].join(" "))).unwrap();
+        assert_eq!(cards[0].points().unwrap(), 1 << 63);
+    }
+
+    #[test]
+    fn rejects_overflowing_copies() {
+        let input: Vec<String> = (1..=100).map(|n| format!("Card {n}: 1 2 | 1 2")).collect();
+        let cards = Day4::parse(&input.join("\n")).unwrap();
+
+        let error = copy_counts(&cards).unwrap_err();
+        assert!(error.to_string().starts_with("Too many copies of card"));
+        assert!(Day4::part_two(&cards).is_err());
+
+        assert_eq!(copy_counts(&cards[..10]).unwrap()[9], 143);
+    }
+}
@@ -0,0 +1,274 @@
+use std::ops::Range;
+
+use anyhow::{anyhow, bail, Result};
+use log::{debug, trace};
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::line_ending,
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{
+    parse::{blank_line, labelled, numbers, paragraphs, parse_all},
+    solver::Solver,
+};
+
+#[derive(Debug)]
+pub struct SeedData {
+    seeds: Vec<u64>,
+}
+
+/// One line of a map: `size` values starting at `source` go to the values
+/// starting at `destination`. Neither range runs past `u64::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapRange {
+    destination: u64,
+    source: u64,
+    size: u64,
+}
+
+impl MapRange {
+    fn source_end(&self) -> u64 {
+        self.source + self.size
+    }
+
+    fn apply(&self, value: u64) -> u64 {
+        value - self.source + self.destination
+    }
+}
+
+/// The ranges of one map, sorted by their source start. Values outside all
+/// of them map to themselves.
+#[derive(Debug)]
+pub struct MapData {
+    ranges: Vec<MapRange>,
+}
+
+impl MapData {
+    fn new(lines: Vec<Vec<u64>>) -> Result<Self> {
+        let mut ranges = lines
+            .into_iter()
+            .map(|line| match line.as_slice() {
+                &[destination, source, size] => {
+                    if source.checked_add(size).is_none() || destination.checked_add(size).is_none()
+                    {
+                        bail!("Map line {:?} runs past the largest value", line);
+                    }
+                    Ok(MapRange {
+                        destination,
+                        source,
+                        size,
+                    })
+                }
+                _ => bail!("Expected three numbers in a map line, got {:?}", line),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        ranges.sort_by_key(|range| range.source);
+
+        Ok(MapData { ranges })
+    }
+
+    pub fn map(&self, value: u64) -> u64 {
+        self.ranges
+            .iter()
+            .find(|range| (range.source..range.source_end()).contains(&value))
+            .map_or(value, |range| range.apply(value))
+    }
+
+    /// Maps a whole range of values at once, splitting it wherever it
+    /// crosses the boundary of a map range.
+    pub fn map_range(&self, values: Range<u64>) -> Vec<Range<u64>> {
+        let mut result = vec![];
+        let mut cursor = values.start;
+
+        for range in &self.ranges {
+            if cursor >= values.end || range.source >= values.end {
+                break;
+            }
+            if range.source_end() <= cursor {
+                continue;
+            }
+
+            if range.source > cursor {
+                result.push(cursor..range.source);
+                cursor = range.source;
+            }
+
+            let stop = range.source_end().min(values.end);
+            result.push(range.apply(cursor)..range.apply(stop));
+            cursor = stop;
+        }
+
+        if cursor < values.end {
+            result.push(cursor..values.end);
+        }
+
+        trace!("{:?} -> {:?}", values, result);
+        result
+    }
+
+    pub fn map_ranges(&self, values: &[Range<u64>]) -> Vec<Range<u64>> {
+        values
+            .iter()
+            .flat_map(|values| self.map_range(values.clone()))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: SeedData,
+    maps: Vec<MapData>,
+}
+
+impl Almanac {
+    /// The location of a single seed.
+    pub fn location(&self, seed: u64) -> u64 {
+        self.maps.iter().fold(seed, |value, map| map.map(value))
+    }
+
+    /// The location ranges of the given seed ranges, after passing them
+    /// through all maps.
+    pub fn location_ranges(&self, seeds: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        self.maps
+            .iter()
+            .fold(seeds, |ranges, map| map.map_ranges(&ranges))
+    }
+
+    /// The seeds read as pairs of a start and a length.
+    pub fn seed_ranges(&self) -> Result<Vec<Range<u64>>> {
+        let seeds = &self.seeds.seeds;
+        if !seeds.len().is_multiple_of(2) {
+            bail!(
+                "Expected pairs of seed starts and lengths, got {} numbers",
+                seeds.len()
+            );
+        }
+
+        seeds
+            .chunks_exact(2)
+            .map(|pair| {
+                let end = pair[0].checked_add(pair[1]).ok_or_else(|| {
+                    anyhow!(
+                        "Seed range {} {} runs past the largest value",
+                        pair[0],
+                        pair[1]
+                    )
+                })?;
+                Ok(pair[0]..end)
+            })
+            .collect()
+    }
+}
+
+fn parse_seeds(input: &str) -> IResult<&str, SeedData> {
+    let (input, seeds) = labelled("seeds", numbers)(input)?;
+    Ok((input, SeedData { seeds }))
+}
+
+fn parse_maps(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    let (input, _) = take_until("map:")(input)?;
+    let (input, _) = tag("map:")(input)?;
+    let (input, _) = line_ending(input)?;
+    separated_list1(line_ending, numbers)(input)
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY: u32 = 5;
+
+    type Input = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (seeds, maps) = parse_all(
+            input,
+            separated_pair(parse_seeds, blank_line, paragraphs(parse_maps)),
+        )?;
+
+        let maps = maps
+            .into_iter()
+            .map(MapData::new)
+            .collect::<Result<Vec<_>>>()?;
+
+        trace!("{:?} {:?}", seeds, maps);
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_one(almanac: &Self::Input) -> Result<Self::Answer1> {
+        almanac
+            .seeds
+            .seeds
+            .iter()
+            .map(|&seed| {
+                let location = almanac.location(seed);
+                debug!("Seed {} -> location {}", seed, location);
+                location
+            })
+            .min()
+            .ok_or_else(|| anyhow!("No seeds to map"))
+    }
+
+    fn part_two(almanac: &Self::Input) -> Result<Self::Answer2> {
+        let locations = almanac.location_ranges(almanac.seed_ranges()?);
+        debug!("{} location ranges", locations.len());
+
+        locations
+            .iter()
+            .filter(|range| !range.is_empty())
+            .map(|range| range.start)
+            .min()
+            .ok_or_else(|| anyhow!("No seeds to map"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map() -> MapData {
+        // seed-to-soil map of the example
+        MapData::new(vec![vec![50, 98, 2], vec![52, 50, 48]]).unwrap()
+    }
+
+    #[test]
+    fn maps_single_values() {
+        let map = map();
+        assert_eq!(map.map(10), 10);
+        assert_eq!(map.map(50), 52);
+        assert_eq!(map.map(97), 99);
+        assert_eq!(map.map(99), 51);
+        assert_eq!(map.map(100), 100);
+    }
+
+    #[test]
+    fn splits_ranges_at_map_boundaries() {
+        let map = map();
+        assert_eq!(map.map_range(0..10), vec![0..10]);
+        assert_eq!(map.map_range(45..55), vec![45..50, 52..57]);
+        assert_eq!(map.map_range(95..105), vec![97..100, 50..52, 100..105]);
+        assert_eq!(map.map_range(60..60), vec![]);
+
+        assert!(MapData::new(vec![vec![1, 2]]).is_err());
+    }
+
+    #[test]
+    fn rejects_ranges_past_the_largest_value() {
+        assert!(MapData::new(vec![vec![0, u64::MAX, 2]]).is_err());
+        assert!(MapData::new(vec![vec![u64::MAX, 0, 2]]).is_err());
+        assert!(MapData::new(vec![vec![0, u64::MAX - 1, 1]]).is_ok());
+
+        let almanac = Day5::parse(&format!(
+            "seeds: {} 2\n\nseed-to-soil map:\n0 0 1",
+            u64::MAX
+        ))
+        .unwrap();
+        assert!(almanac.seed_ranges().is_err());
+        assert!(Day5::part_two(&almanac).is_err());
+    }
+}
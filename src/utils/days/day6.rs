@@ -0,0 +1,89 @@
+// Time:      7  15   30
+// Distance:  9  40  200
+
+use anyhow::{Context, Result};
+use log::debug;
+use nom::{
+    character::complete::{digit1, line_ending, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{
+    parse::{labelled, parse_all},
+    solver::{not_implemented, Solver},
+};
+
+/// The digit groups of the time and the distance line, as written.
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+fn digit_groups(input: &str) -> IResult<&str, Vec<String>> {
+    separated_list1(space1, map(digit1, |digits: &str| digits.to_owned()))(input)
+}
+
+/// The number the groups make when the spaces between them are ignored.
+fn joined(groups: &[String]) -> Result<u64> {
+    let digits = groups.concat();
+    digits
+        .parse()
+        .with_context(|| format!("{digits} is too large"))
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY: u32 = 6;
+
+    type Input = Sheet;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (times, distances) = parse_all(
+            input,
+            separated_pair(
+                labelled("Time", digit_groups),
+                line_ending,
+                labelled("Distance", digit_groups),
+            ),
+        )?;
+
+        Ok(Sheet { times, distances })
+    }
+
+    fn part_one(_sheet: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(sheet: &Self::Input) -> Result<Self::Answer2> {
+        let time = joined(&sheet.times)?;
+        let distance = joined(&sheet.distances)?;
+
+        debug!("time {}, distance {}", time, distance);
+
+        Ok((0..=time)
+            .map(|hold| (time - hold) * hold)
+            .filter(|traveled| *traveled > distance)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseError;
+
+    #[test]
+    fn reports_errors_in_the_original_text() {
+        let error = Day6::parse("Time:      7  15   30\nDistance:  9  4x  200\n").unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (2, 16));
+        assert_eq!(error.snippet, "Distance:  9  4x  200");
+    }
+}
@@ -0,0 +1,182 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use anyhow::Result;
+use log::{debug, trace};
+use nom::{
+    bytes::complete::tag, character::complete::satisfy, combinator::recognize, multi::count,
+    sequence::terminated, IResult,
+};
+
+use crate::{
+    parse::{number, parse_lines},
+    solver::{not_implemented, Solver},
+};
+
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
+enum Type {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    hand: String,
+    bid: u64,
+}
+
+impl Hand {
+    fn get_card_values(&self) -> Vec<u32> {
+        self.hand.chars().filter_map(card_value).collect()
+    }
+
+    fn get_type(&self) -> Type {
+        let mut counts = HashMap::new();
+
+        let (jokers, non_jokers): (Vec<char>, Vec<char>) =
+            self.hand.to_owned().chars().partition(|c| *c == 'J');
+
+        for c in non_jokers {
+            *counts.entry(c).or_insert(0) += 1;
+        }
+
+        let Some(max) = counts.keys().max().copied() else {
+            // Only jokers
+            return Type::FiveOfAKind;
+        };
+
+        *counts.entry(max).or_default() += jokers.len();
+
+        trace!("{} {:?}", self.hand, counts);
+
+        let mut has = HashMap::new();
+        let mut two_pair = false;
+
+        for (_, count) in counts {
+            if count == 5 {
+                has.insert(5, true);
+            }
+            if count == 4 {
+                has.insert(4, true);
+            }
+            if count == 3 {
+                has.insert(3, true);
+            }
+            if count == 2 {
+                if has.contains_key(&2) {
+                    two_pair = true;
+                }
+                has.insert(2, true);
+            }
+        }
+
+        if has.contains_key(&5) {
+            return Type::FiveOfAKind;
+        }
+
+        if has.contains_key(&4) {
+            return Type::FourOfAKind;
+        }
+
+        if has.contains_key(&3) && has.contains_key(&2) {
+            return Type::FullHouse;
+        }
+
+        if has.contains_key(&3) {
+            return Type::ThreeOfAKind;
+        }
+
+        if two_pair {
+            return Type::TwoPair;
+        }
+
+        if has.contains_key(&2) {
+            return Type::OnePair;
+        }
+
+        Type::HighCard
+    }
+}
+
+fn card_value(card: char) -> Option<u32> {
+    match card {
+        '2'..='9' => card.to_digit(10),
+        'T' => Some(10),
+        'J' => Some(11),
+        'Q' => Some(12),
+        'K' => Some(13),
+        'A' => Some(14),
+        _ => None,
+    }
+}
+
+fn parse(line: &str) -> IResult<&str, Hand> {
+    let (input, hand) = terminated(
+        recognize(count(satisfy(|c| card_value(c).is_some()), 5)),
+        tag(" "),
+    )(line)?;
+    let (input, bid) = number(input)?;
+    Ok((
+        input,
+        Hand {
+            hand: hand.to_owned(),
+            bid,
+        },
+    ))
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<Hand>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, parse)?)
+    }
+
+    fn part_one(_hands: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(hands: &Self::Input) -> Result<Self::Answer2> {
+        let mut hands: Vec<&Hand> = hands.iter().collect();
+
+        hands.sort_by(|a, b| {
+            if a.get_type() < b.get_type() {
+                return Ordering::Less;
+            }
+
+            if a.get_type() > b.get_type() {
+                return Ordering::Greater;
+            }
+
+            if a.get_card_values() < b.get_card_values() {
+                return Ordering::Less;
+            }
+
+            if a.get_card_values() > b.get_card_values() {
+                return Ordering::Greater;
+            }
+
+            Ordering::Equal
+        });
+
+        debug!("{:?}", hands);
+
+        let result: usize = hands
+            .iter()
+            .enumerate()
+            .map(|(i, h)| (i + 1) * h.bid as usize)
+            .sum();
+
+        Ok(result)
+    }
+}
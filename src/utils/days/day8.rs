@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use log::trace;
+use nom::{
+    character::complete::{line_ending, multispace1, one_of},
+    combinator::map,
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+
+use crate::{
+    parse::{key_pair, parse_all},
+    solver::{not_implemented, Solver},
+};
+
+#[derive(Debug)]
+pub struct MapEntry {
+    id: String,
+    left_direction: String,
+    right_direction: String,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    directions: Vec<char>,
+    entries: HashMap<String, MapEntry>,
+}
+
+fn parse_line(line: &str) -> IResult<&str, MapEntry> {
+    map(key_pair, |(id, (left_direction, right_direction))| {
+        MapEntry {
+            id: id.to_owned(),
+            left_direction: left_direction.to_owned(),
+            right_direction: right_direction.to_owned(),
+        }
+    })(line)
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY: u32 = 8;
+
+    type Input = Network;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (directions, entries) = parse_all(
+            input,
+            separated_pair(
+                many1(one_of("LR")),
+                multispace1,
+                separated_list1(line_ending, parse_line),
+            ),
+        )?;
+
+        let entries: HashMap<String, MapEntry> =
+            entries.into_iter().map(|e| (e.id.to_owned(), e)).collect();
+
+        trace!("{:?} {:?}", directions, entries);
+
+        Ok(Network {
+            directions,
+            entries,
+        })
+    }
+
+    fn part_one(_network: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(network: &Self::Input) -> Result<Self::Answer2> {
+        let Network {
+            directions,
+            entries,
+        } = network;
+
+        let mut current: Vec<String> = entries
+            .keys()
+            .filter(|k| k.ends_with('A'))
+            .map(|x| x.to_owned())
+            .collect();
+
+        let done = |nodes: &Vec<String>| nodes.iter().all(|x| x.ends_with('Z'));
+
+        let mut iteration = 0usize;
+
+        while !done(&current) {
+            let index = iteration % directions.len();
+            let direction = directions[index];
+
+            let new_current = current
+                .iter()
+                .map(|c| {
+                    let map_entry = entries
+                        .get(c)
+                        .ok_or_else(|| anyhow!("Unknown node {}", c))?;
+
+                    match direction {
+                        'L' => Ok(map_entry.left_direction.to_owned()),
+                        'R' => Ok(map_entry.right_direction.to_owned()),
+                        default => bail!("Unexpected direction {}", default),
+                    }
+                })
+                .collect::<Result<Vec<String>>>()?;
+
+            trace!(
+                "Index {}, direction {}, current {:?}, new_current {:?}",
+                index,
+                direction,
+                current,
+                new_current
+            );
+
+            current = new_current;
+            iteration += 1;
+        }
+
+        Ok(iteration)
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    parse::{numbers, parse_lines},
+    solver::{not_implemented, Solver},
+};
+
+fn differences(vec: &VecDeque<i64>) -> VecDeque<i64> {
+    vec.iter()
+        .zip(vec.iter().skip(1))
+        .map(|(current, following)| following - current)
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct History {
+    values: Vec<VecDeque<i64>>,
+}
+
+impl History {
+    fn new(values: Vec<i64>) -> Self {
+        History {
+            values: Vec::from([VecDeque::from(values)]),
+        }
+    }
+
+    fn fill_differences(&mut self) {
+        while let Some(last) = self.values.last() {
+            if last.is_empty() || last.iter().all(|x| *x == 0) {
+                break;
+            }
+
+            let next = differences(last);
+            self.values.push(next);
+        }
+    }
+
+    fn extrapolate(&mut self) -> Result<i64> {
+        let mut current_diff = 0;
+        for values in self.values.iter_mut().rev() {
+            let first = values
+                .front()
+                .ok_or_else(|| anyhow!("Differences never become zero"))?;
+            let new_value = first - current_diff;
+            values.push_front(new_value);
+            current_diff = new_value;
+        }
+        Ok(current_diff)
+    }
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<History>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let histories = parse_lines(input, numbers)?;
+        Ok(histories.into_iter().map(History::new).collect())
+    }
+
+    fn part_one(_histories: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(histories: &Self::Input) -> Result<Self::Answer2> {
+        let mut histories = histories.clone();
+
+        let mut sum = 0;
+        for h in &mut histories {
+            h.fill_differences();
+            sum += h.extrapolate()?;
+        }
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,231 @@
+//! Fetching puzzle inputs that are missing from the input directory. Fetched
+//! inputs are written to the input directory, so every day is only fetched once.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+/// Session cookie used to download inputs from the Advent of Code website.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the website, e.g. to point at a local mock server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+/// Directory to copy missing inputs from instead of downloading them.
+pub const MIRROR_VAR: &str = "AOC_INPUT_MIRROR";
+
+const BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2023;
+
+pub trait InputProvider {
+    fn fetch(&self, day: u32) -> Result<String>;
+}
+
+/// Downloads inputs from the website using a session cookie.
+pub struct HttpProvider {
+    base_url: String,
+    session: String,
+}
+
+impl HttpProvider {
+    pub fn new(session: impl Into<String>) -> Self {
+        HttpProvider {
+            base_url: BASE_URL.to_owned(),
+            session: session.into(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+impl InputProvider for HttpProvider {
+    fn fetch(&self, day: u32) -> Result<String> {
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "github.com/msc94/adventofcode2023")
+            .call()
+            .with_context(|| format!("Could not download {url}"))?
+            .into_string()
+            .with_context(|| format!("Could not read the response from {url}"))
+    }
+}
+
+/// Copies inputs from another directory, for working offline.
+pub struct DirectoryProvider {
+    dir: PathBuf,
+}
+
+impl DirectoryProvider {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        DirectoryProvider { dir: dir.into() }
+    }
+}
+
+impl InputProvider for DirectoryProvider {
+    fn fetch(&self, day: u32) -> Result<String> {
+        let path = self.dir.join(format!("day{day}.txt"));
+        fs::read_to_string(&path).with_context(|| format!("Could not read {}", path.display()))
+    }
+}
+
+/// The provider configured through the environment, if any. A mirror
+/// directory takes precedence over downloading.
+pub fn provider_from_env() -> Option<Box<dyn InputProvider>> {
+    if let Some(dir) = env::var_os(MIRROR_VAR) {
+        return Some(Box::new(DirectoryProvider::new(dir)));
+    }
+
+    let session = env::var(SESSION_VAR).ok()?;
+    let provider = match env::var(BASE_URL_VAR) {
+        Ok(base_url) => HttpProvider::new(session).with_base_url(base_url),
+        Err(_) => HttpProvider::new(session),
+    };
+    Some(Box::new(provider))
+}
+
+/// The day of a real puzzle input file name like `day5.txt`. Examples are
+/// not fetched, as they are not available separately.
+pub fn input_day(name: &str) -> Option<u32> {
+    name.strip_prefix("day")?.strip_suffix(".txt")?.parse().ok()
+}
+
+/// Whether the input at `path` still has to be fetched. Empty files count as
+/// missing, since `aoc new` creates them as placeholders.
+pub fn is_missing(path: &Path) -> bool {
+    fs::metadata(path).map_or(true, |metadata| metadata.len() == 0)
+}
+
+/// Makes sure the input for `day` exists in `dir`, fetching it with
+/// `provider` if it does not. Returns the path of the input.
+pub fn cache_input(dir: &Path, day: u32, provider: &dyn InputProvider) -> Result<PathBuf> {
+    let path = dir.join(format!("day{day}.txt"));
+    if !is_missing(&path) {
+        return Ok(path);
+    }
+
+    let input = provider
+        .fetch(day)
+        .with_context(|| format!("Could not fetch the input for day {day}"))?;
+    if input.is_empty() {
+        return Err(anyhow!("Fetched an empty input for day {day}"));
+    }
+
+    fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    fs::write(&path, input).with_context(|| format!("Could not cache {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, process};
+
+    use super::*;
+
+    struct FakeProvider {
+        input: Option<&'static str>,
+        calls: Cell<u32>,
+    }
+
+    impl FakeProvider {
+        fn new(input: Option<&'static str>) -> Self {
+            FakeProvider {
+                input,
+                calls: Cell::new(0),
+            }
+        }
+    }
+
+    impl InputProvider for FakeProvider {
+        fn fetch(&self, day: u32) -> Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            self.input
+                .map(|input| input.to_owned())
+                .ok_or_else(|| anyhow!("No input for day {day}"))
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_missing_input_once() {
+        let dir = temp_dir("missing");
+        let provider = FakeProvider::new(Some("1 2 3\n"));
+
+        let path = cache_input(&dir, 9, &provider).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert_eq!(path, dir.join("day9.txt"));
+
+        cache_input(&dir, 9, &provider).unwrap();
+        assert_eq!(provider.calls.get(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn keeps_existing_input() {
+        let dir = temp_dir("existing");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day3.txt"), "local").unwrap();
+        let provider = FakeProvider::new(Some("remote"));
+
+        let path = cache_input(&dir, 3, &provider).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "local");
+        assert_eq!(provider.calls.get(), 0);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn replaces_empty_placeholder() {
+        let dir = temp_dir("placeholder");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day11.txt"), "").unwrap();
+        let provider = FakeProvider::new(Some("#..\n"));
+
+        let path = cache_input(&dir, 11, &provider).unwrap();
+        assert_eq!(fs::read_to_string(path).unwrap(), "#..\n");
+        assert_eq!(provider.calls.get(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_fetch_is_not_cached() {
+        let dir = temp_dir("failed");
+        let provider = FakeProvider::new(None);
+
+        assert!(cache_input(&dir, 4, &provider).is_err());
+        assert!(!dir.join("day4.txt").exists());
+    }
+
+    #[test]
+    fn directory_provider_reads_day_files() {
+        let dir = temp_dir("mirror");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day5.txt"), "seeds: 1").unwrap();
+
+        let provider = DirectoryProvider::new(&dir);
+        assert_eq!(provider.fetch(5).unwrap(), "seeds: 1");
+        assert!(provider.fetch(6).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn only_real_inputs_are_fetched() {
+        assert_eq!(input_day("day5.txt"), Some(5));
+        assert_eq!(input_day("day17.txt"), Some(17));
+        assert_eq!(input_day("day5_example.txt"), None);
+        assert_eq!(input_day("answers.toml"), None);
+    }
+}
@@ -0,0 +1,457 @@
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::geometry::Point;
+
+/// A rectangular grid stored row by row. Coordinates are `(x, y)`, with `x`
+/// the column and `y` the row, counted from the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const NEIGHBOURS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("Row {y} has {} cells, expected {width}", row.len());
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line, converting every character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect::<Result<Vec<Vec<T>>>>()?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like `get`, but for coordinates that may have stepped off the grid.
+    pub fn get_signed(&self, x: i64, y: i64) -> Option<&T> {
+        let (x, y) = self.to_unsigned(x, y)?;
+        self.get(x, y)
+    }
+
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get_signed(point.x, point.y)
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        self.to_unsigned(x, y).is_some()
+    }
+
+    fn to_unsigned(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        let x = usize::try_from(x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(y).ok().filter(|y| *y < self.height)?;
+        Some((x, y))
+    }
+
+    fn offsets(
+        &self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| self.to_unsigned(x as i64 + dx, y as i64 + dy))
+    }
+
+    /// The up to four cells sharing an edge with `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS4)
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(x, y, &NEIGHBOURS8)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` rejects a size of zero, which an empty grid would have.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All cells with their coordinates, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Builds a grid of the given size from the cells of this one.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+}
+
+/// A run of digits within a row, read as a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberSpan {
+    pub value: u64,
+    pub row: usize,
+    /// First column of the number.
+    pub start: usize,
+    /// Column after the last digit.
+    pub end: usize,
+    /// The cells around the number that are on the grid, row by row.
+    pub neighbours: Vec<Point>,
+}
+
+impl NumberSpan {
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        (self.start..self.end).map(|x| Point::from((x, self.row)))
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point
+            .to_grid()
+            .is_some_and(|(x, y)| y == self.row && (self.start..self.end).contains(&x))
+    }
+}
+
+impl Grid<char> {
+    pub fn from_chars(input: &str) -> Result<Self> {
+        Grid::parse(input, Ok)
+    }
+
+    /// Every number written in the grid, left to right and top to bottom.
+    /// Numbers end at the end of their row.
+    pub fn number_spans(&self) -> Result<Vec<NumberSpan>> {
+        let mut spans = vec![];
+
+        for (y, row) in self.rows().enumerate() {
+            let mut x = 0;
+            while x < row.len() {
+                if !row[x].is_ascii_digit() {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                let mut value: u64 = 0;
+                while let Some(digit) = row.get(x).and_then(|c| c.to_digit(10)) {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(u64::from(digit)))
+                        .ok_or_else(|| anyhow!("Number at ({start}, {y}) is too large"))?;
+                    x += 1;
+                }
+
+                spans.push(self.number_span(value, y, start, x));
+            }
+        }
+
+        Ok(spans)
+    }
+
+    fn number_span(&self, value: u64, row: usize, start: usize, end: usize) -> NumberSpan {
+        let mut neighbours = vec![];
+        for y in row as i64 - 1..=row as i64 + 1 {
+            for x in start as i64 - 1..=end as i64 {
+                let inside = y == row as i64 && x >= start as i64 && x < end as i64;
+                if !inside && self.contains(x, y) {
+                    neighbours.push(Point::new(x, y));
+                }
+            }
+        }
+
+        NumberSpan {
+            value,
+            row,
+            start,
+            end,
+            neighbours,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({x}, {y}) is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside of the {width}x{height} grid"))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point).unwrap_or_else(|| {
+            panic!(
+                "{point} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        point
+            .to_grid()
+            .and_then(|(x, y)| self.get_mut(x, y))
+            .unwrap_or_else(|| panic!("{point} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Prints one row per line, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_chars("abc\ndef\n").unwrap()
+    }
+
+    #[test]
+    fn parses_lines() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert!(Grid::from_chars("ab\nc").is_err());
+        assert!(Grid::parse("12\n3x", |c| c.to_digit(10).ok_or(anyhow::anyhow!("{c}"))).is_err());
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = grid();
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&'e'));
+        assert!(grid.contains(2, 1));
+        assert!(!grid.contains(2, 2));
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = grid();
+        let four: Vec<_> = grid.neighbours4(0, 0).collect();
+        assert_eq!(four, vec![(1, 0), (0, 1)]);
+
+        let eight: Vec<_> = grid.neighbours8(1, 0).collect();
+        assert_eq!(eight, vec![(2, 0), (2, 1), (1, 1), (0, 1), (0, 0)]);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.rows().count(), 2);
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+
+        let cells: Vec<_> = grid.cells().map(|(p, c)| (p, *c)).take(4).collect();
+        assert_eq!(
+            cells,
+            vec![((0, 0), 'a'), ((1, 0), 'b'), ((2, 0), 'c'), ((0, 1), 'd')]
+        );
+    }
+
+    #[test]
+    fn finds_number_spans() {
+        let grid = Grid::from_chars("12.\n..3\n4.*\n").unwrap();
+        let spans = grid.number_spans().unwrap();
+
+        let values: Vec<_> = spans
+            .iter()
+            .map(|s| (s.value, s.row, s.start, s.end))
+            .collect();
+        assert_eq!(values, vec![(12, 0, 0, 2), (3, 1, 2, 3), (4, 2, 0, 1)]);
+
+        // A number at the end of a row does not run into the next row.
+        let points =
+            |cells: &[(i64, i64)]| cells.iter().map(|&c| Point::from(c)).collect::<Vec<_>>();
+        assert_eq!(
+            spans[0].neighbours,
+            points(&[(2, 0), (0, 1), (1, 1), (2, 1)])
+        );
+        assert_eq!(
+            spans[1].neighbours,
+            points(&[(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)])
+        );
+        assert!(spans[0].contains(Point::new(1, 0)));
+        assert!(!spans[0].contains(Point::new(-1, 0)));
+        assert_eq!(
+            spans[0].cells().collect::<Vec<_>>(),
+            points(&[(0, 0), (1, 0)])
+        );
+
+        assert!(Grid::from_chars("99999999999999999999999")
+            .unwrap()
+            .number_spans()
+            .is_err());
+    }
+
+    #[test]
+    fn rotates_and_transposes() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counterclockwise(),
+            grid.clone()
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    fetch::{cache_input, input_day, is_missing, provider_from_env},
+    input_dir,
+};
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file in the input directory, e.g. `day5.txt`.
+    Named(String),
+    /// Any file on disk.
+    File(PathBuf),
+    Stdin,
+    /// Input held in memory, mostly useful for tests.
+    Text(String),
+}
+
+impl InputSource {
+    pub fn named(name: impl Into<String>) -> Self {
+        InputSource::Named(name.into())
+    }
+
+    pub fn text(text: impl Into<String>) -> Self {
+        InputSource::Text(text.into())
+    }
+
+    /// Opens the input for reading, resolving named inputs in `input_dir()`.
+    pub fn open(&self) -> Result<Box<dyn BufRead>> {
+        self.open_in(&input_dir())
+    }
+
+    /// Opens the input for reading, resolving named inputs in `dir`.
+    pub fn open_in(&self, dir: &Path) -> Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::Named(name) => {
+                let mut path = dir.join(name);
+                if is_missing(&path) {
+                    if let (Some(day), Some(provider)) = (input_day(name), provider_from_env()) {
+                        path = cache_input(dir, day, provider.as_ref())?;
+                    }
+                }
+
+                let file = File::open(&path).with_context(|| {
+                    format!(
+                        "Could not read input {name} from {} (set {} or pass --input-dir to use another directory)",
+                        path.display(),
+                        crate::INPUT_DIR_VAR
+                    )
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            InputSource::File(path) => {
+                let file = File::open(path)
+                    .with_context(|| format!("Could not read {}", path.display()))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Text(text) => Ok(Box::new(Cursor::new(text.clone().into_bytes()))),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        self.read_in(&input_dir())
+    }
+
+    pub fn read_in(&self, dir: &Path) -> Result<String> {
+        if let InputSource::Text(text) = self {
+            return Ok(text.clone());
+        }
+
+        let mut input = String::new();
+        self.open_in(dir)?
+            .read_to_string(&mut input)
+            .with_context(|| format!("Could not read {self}"))?;
+        Ok(input)
+    }
+
+    pub fn lines(&self) -> Result<Vec<String>> {
+        let input = self.read()?;
+        Ok(input.lines().map(|s| s.to_string()).collect())
+    }
+}
+
+/// `-` means stdin, anything else is a path.
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Named(name) => write!(f, "{name}"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::*;
+
+    #[test]
+    fn reads_inline_text() {
+        let source = InputSource::text("1 2\n3 4\n");
+        assert_eq!(source.read_in(Path::new("unused")).unwrap(), "1 2\n3 4\n");
+
+        let mut line = String::new();
+        source
+            .open_in(Path::new("unused"))
+            .unwrap()
+            .read_line(&mut line)
+            .unwrap();
+        assert_eq!(line, "1 2\n");
+        assert_eq!(source.to_string(), "<text>");
+    }
+
+    #[test]
+    fn parses_dash_as_stdin() {
+        assert_eq!("-".parse::<InputSource>().unwrap(), InputSource::Stdin);
+        assert_eq!(
+            "inputs/day3.txt".parse::<InputSource>().unwrap(),
+            InputSource::File(PathBuf::from("inputs/day3.txt"))
+        );
+    }
+
+    #[test]
+    fn reads_named_inputs_from_the_directory() {
+        let dir = env::temp_dir().join(format!("aoc-input-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day3_example.txt"), "467..114..").unwrap();
+
+        let source = InputSource::named("day3_example.txt");
+        assert_eq!(source.read_in(&dir).unwrap(), "467..114..");
+
+        let error = InputSource::named("day3_example_2.txt")
+            .read_in(&dir)
+            .unwrap_err();
+        let message = format!("{error:#}");
+        assert!(message.contains("day3_example_2.txt"));
+        assert!(message.contains(&dir.display().to_string()));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+//! Diagnostic output for the solutions, built on the `log` crate.
+//!
+//! Solutions log intermediate results at `debug` and dump their parsed input
+//! and every step at `trace`. Output goes to stderr and is controlled with
+//! `$AOC_LOG`, which takes `env_logger` directives where a bare `dayN` stands
+//! for that day's module, e.g. `AOC_LOG=day5=trace,day7=debug`.
+
+use std::str::FromStr;
+
+use env_logger::{filter, Builder, Logger};
+use log::{LevelFilter, Log, Metadata, Record};
+
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// A log filter where `dayN` matches only the module of that day, so `day1`
+/// does not cover `day10`. Other directives work as in `env_logger`, where
+/// a target also covers every target it is a prefix of.
+pub struct DayFilter {
+    days: Vec<(String, LevelFilter)>,
+    rest: filter::Filter,
+}
+
+impl DayFilter {
+    fn day_level(&self, target: &str) -> Option<LevelFilter> {
+        self.days
+            .iter()
+            .rev()
+            .find(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .map(|(_, level)| *level)
+    }
+
+    pub fn enabled(&self, metadata: &Metadata) -> bool {
+        match self.day_level(metadata.target()) {
+            Some(level) => metadata.level() <= level,
+            None => self.rest.enabled(metadata),
+        }
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.days
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.rest.filter(), Ord::max)
+    }
+}
+
+/// Expands `dayN` targets in a filter to the module of that day. Everything
+/// not covered by a directive is logged up to `default`.
+pub fn expand_filter(filter: &str, default: LevelFilter) -> DayFilter {
+    let mut days = vec![];
+    let mut rest = vec![];
+
+    for directive in filter.split(',').filter(|d| !d.is_empty()) {
+        let (target, level) = match directive.split_once('=') {
+            Some((target, level)) => (target, LevelFilter::from_str(level).ok()),
+            None => (directive, Some(LevelFilter::Trace)),
+        };
+        let is_day = target
+            .strip_prefix("day")
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+
+        match level {
+            Some(level) if is_day => {
+                days.push((format!("{}::{target}", crate::days::MODULE), level));
+            }
+            _ => rest.push(directive),
+        }
+    }
+
+    DayFilter {
+        days,
+        rest: filter::Builder::new()
+            .filter_level(default)
+            .parse(&rest.join(","))
+            .build(),
+    }
+}
+
+/// Writes what `filter` lets through with `env_logger`'s formatting.
+struct DayLogger {
+    filter: DayFilter,
+    output: Logger,
+}
+
+impl Log for DayLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.filter.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            self.output.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.output.flush();
+    }
+}
+
+fn level(verbosity: u8) -> LevelFilter {
+    match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    }
+}
+
+/// Sets up logging. An explicit `filter` wins over `$AOC_LOG`, which wins
+/// over the level given by `verbosity` (0 is warnings only, 3 is everything).
+/// Does nothing if logging was already set up.
+pub fn init(verbosity: u8, filter: Option<&str>) {
+    let filter = filter
+        .map(|f| f.to_owned())
+        .or_else(|| std::env::var(LOG_VAR).ok())
+        .unwrap_or_default();
+    let filter = expand_filter(&filter, level(verbosity));
+    let max_level = filter.max_level();
+
+    let output = Builder::new()
+        .format_timestamp(None)
+        .filter_level(LevelFilter::Trace)
+        .build();
+
+    if log::set_boxed_logger(Box::new(DayLogger { filter, output })).is_ok() {
+        log::set_max_level(max_level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use log::Level;
+
+    use super::*;
+
+    fn enabled(filter: &DayFilter, target: &str, level: Level) -> bool {
+        filter.enabled(&Metadata::builder().target(target).level(level).build())
+    }
+
+    #[test]
+    fn days_match_exactly() {
+        let filter = expand_filter("day1=debug", LevelFilter::Warn);
+        assert!(enabled(&filter, "utils::days::day1", Level::Debug));
+        assert!(enabled(&filter, "utils::days::day1::inner", Level::Debug));
+        assert!(!enabled(&filter, "utils::days::day1", Level::Trace));
+        assert!(!enabled(&filter, "utils::days::day10", Level::Debug));
+        assert!(enabled(&filter, "utils::days::day10", Level::Warn));
+
+        let filter = expand_filter("day1=debug,day10=trace", LevelFilter::Warn);
+        assert!(!enabled(&filter, "utils::days::day1", Level::Trace));
+        assert!(enabled(&filter, "utils::days::day10", Level::Trace));
+        assert_eq!(filter.max_level(), LevelFilter::Trace);
+    }
+
+    #[test]
+    fn keeps_other_directives() {
+        let filter = expand_filter("info,utils::runner=debug,day5", LevelFilter::Warn);
+        assert!(enabled(&filter, "utils::days::day5", Level::Trace));
+        assert!(enabled(&filter, "utils::days::day7", Level::Info));
+        assert!(!enabled(&filter, "utils::days::day7", Level::Debug));
+        assert!(enabled(&filter, "utils::runner", Level::Debug));
+
+        let filter = expand_filter("", LevelFilter::Info);
+        assert!(enabled(&filter, "utils::days::day3", Level::Info));
+        assert!(!enabled(&filter, "utils::days::day3", Level::Debug));
+    }
+}
@@ -0,0 +1,307 @@
+//! Running nom parsers over puzzle inputs with errors that point at the
+//! offending line and column, and combinators for the shapes most puzzle
+//! inputs share.
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{
+        alphanumeric1, char, digit1, line_ending, none_of, one_of, space0, space1,
+    },
+    combinator::{map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many1, many1_count, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, tuple},
+    Err, IResult, Offset,
+};
+
+use crate::grid::Grid;
+
+/// A parse failure at a position in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending line.
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error for the position where `remaining` starts in `input`.
+    /// `remaining` has to be a suffix of `input`.
+    pub fn at(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        let offset = input.offset(remaining);
+        let before = &input[..offset];
+
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            message: message.into(),
+        }
+    }
+
+    fn from_nom(input: &str, error: Err<Error<&str>>) -> Self {
+        match error {
+            Err::Error(e) | Err::Failure(e) => ParseError::at(input, e.input, describe(e.code)),
+            Err::Incomplete(_) => {
+                ParseError::at(input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{:gutter$} | {:>column$}", "", "^", column = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Tag => "unexpected text".to_owned(),
+        ErrorKind::Digit => "expected a number".to_owned(),
+        ErrorKind::Alpha => "expected a letter".to_owned(),
+        ErrorKind::Char | ErrorKind::OneOf | ErrorKind::NoneOf | ErrorKind::Satisfy => {
+            "unexpected character".to_owned()
+        }
+        ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace".to_owned(),
+        ErrorKind::CrLf => "expected a line break".to_owned(),
+        ErrorKind::Eof => "unexpected end of input".to_owned(),
+        ErrorKind::MapRes => "invalid value".to_owned(),
+        kind => format!("parser {} failed", kind.description()),
+    }
+}
+
+/// Runs `parser` over the whole input and fails unless everything but
+/// trailing whitespace is consumed.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O, ParseError> {
+    let (remaining, output) = parser(input).map_err(|e| ParseError::from_nom(input, e))?;
+
+    let rest = remaining.trim_start();
+    if !rest.is_empty() {
+        return Err(ParseError::at(input, rest, "unexpected trailing input"));
+    }
+
+    Ok(output)
+}
+
+/// Runs `parser` on every line of the input. Errors point into the whole
+/// input, so they carry the right line number.
+pub fn parse_lines<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<Vec<O>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (remaining, output) = parser(line).map_err(|e| ParseError::from_nom(input, e))?;
+
+            let rest = remaining.trim_start();
+            if !rest.is_empty() {
+                return Err(ParseError::at(input, rest, "unexpected trailing input"));
+            }
+
+            Ok(output)
+        })
+        .collect()
+}
+
+/// An integer of any type, with an optional sign.
+pub fn number<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// Numbers separated by spaces on a single line, as in `41 48  83`.
+pub fn numbers<T: FromStr>(input: &str) -> IResult<&str, Vec<T>> {
+    separated_list1(space1, number)(input)
+}
+
+/// A `Label N:` header such as `Card   7:`, returning `N`. Spaces after the
+/// colon are consumed as well.
+pub fn header<'a, T: FromStr>(label: &'a str) -> impl FnMut(&'a str) -> IResult<&'a str, T> {
+    delimited(pair(tag(label), space1), number, pair(char(':'), space0))
+}
+
+/// `parser` behind a `Label:` prefix, as in `Time:  7  15  30`.
+pub fn labelled<'a, O>(
+    label: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    preceded(tuple((tag(label), char(':'), space0)), parser)
+}
+
+/// The end of a line followed by at least one empty line.
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    recognize(pair(line_ending, many1_count(line_ending)))(input)
+}
+
+/// Blocks of lines separated by blank lines, each parsed with `parser`.
+pub fn paragraphs<'a, O>(
+    parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>> {
+    separated_list1(blank_line, parser)
+}
+
+/// A `key = (left, right)` record, as in `AAA = (BBB, CCC)`.
+pub fn key_pair(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        delimited(space0, char('='), space0),
+        delimited(
+            char('('),
+            separated_pair(alphanumeric1, pair(char(','), space0), alphanumeric1),
+            char(')'),
+        ),
+    )(input)
+}
+
+/// One row of cells per line. Fails if the rows differ in length.
+pub fn grid<'a, T>(
+    cell: impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>> {
+    map_res(separated_list1(line_ending, many1(cell)), Grid::from_rows)
+}
+
+/// A grid of the raw characters of the input.
+pub fn char_grid(input: &str) -> IResult<&str, Grid<char>> {
+    grid(none_of("\r\n"))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{space1, u32},
+        multi::separated_list1,
+        sequence::preceded,
+    };
+
+    use super::*;
+
+    fn prefixed(input: &str) -> IResult<&str, Vec<u32>> {
+        preceded(tag("n:"), preceded(space1, separated_list1(space1, u32)))(input)
+    }
+
+    #[test]
+    fn parses_every_line() {
+        assert_eq!(
+            parse_lines("n: 1 2\nn: 3\n", prefixed),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let error = parse_lines("n: 1 2\nn: x\n", prefixed).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.snippet, "n: x");
+        assert_eq!(error.message, "expected a number");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a number\n  |\n2 | n: x\n  |    ^"
+        );
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        let error = parse_lines("n: 1 2\nn: 3 x", prefixed).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.message, "unexpected trailing input");
+
+        let error = parse_all("n: 1\nrest", prefixed).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn allows_trailing_whitespace() {
+        assert_eq!(parse_all("n: 1 2\n\n", prefixed), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn parses_numbers_of_any_type() {
+        assert_eq!(number::<i32>("-12 3"), Ok((" 3", -12)));
+        assert_eq!(number::<u64>("+7"), Ok(("", 7)));
+        assert!(number::<u8>("300").is_err());
+        assert!(number::<u32>("-1").is_err());
+
+        assert_eq!(
+            numbers::<u64>("41 48  83 | 1"),
+            Ok((" | 1", vec![41, 48, 83]))
+        );
+    }
+
+    #[test]
+    fn parses_headers_and_labels() {
+        assert_eq!(header::<u32>("Card")("Card   7: 1 2"), Ok(("1 2", 7)));
+        assert!(header::<u32>("Card")("Game 7:").is_err());
+
+        let mut time = labelled("Time", numbers::<u32>);
+        assert_eq!(time("Time:  7  15   30"), Ok(("", vec![7, 15, 30])));
+        assert_eq!(time("Time:71530"), Ok(("", vec![71530])));
+    }
+
+    #[test]
+    fn splits_paragraphs() {
+        let block = separated_list1(line_ending, numbers::<u32>);
+        assert_eq!(
+            parse_all("1 2\n3\n\n4\r\n\r\n\n5 6\n", paragraphs(block)),
+            Ok(vec![
+                vec![vec![1, 2], vec![3]],
+                vec![vec![4]],
+                vec![vec![5, 6]]
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_key_pairs() {
+        assert_eq!(
+            key_pair("AAA = (BBB, CCC)"),
+            Ok(("", ("AAA", ("BBB", "CCC"))))
+        );
+        assert!(key_pair("AAA = (BBB)").is_err());
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = parse_all("#.\n.#\n", char_grid).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[(1, 1)], '#');
+
+        let digits = super::grid(map_res(one_of("0123456789"), |c| {
+            c.to_string().parse::<u32>()
+        }));
+        assert_eq!(parse_all("12\n34", digits).unwrap()[(0, 1)], 3);
+
+        let error = parse_all("#.\n#\n", char_grid).unwrap_err();
+        assert_eq!(error.message, "invalid value");
+    }
+}
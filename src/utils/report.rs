@@ -0,0 +1,125 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    answers::Verdict,
+    runner::{Outcome, Status},
+    solver::Part,
+};
+
+/// One outcome flattened for machine-readable output. `status` is one of
+/// `correct`, `wrong`, `unknown` or `failed`.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Record<'a> {
+    pub day: u32,
+    pub part: Option<u8>,
+    pub input: &'a str,
+    pub answer: Option<&'a str>,
+    pub duration_ms: f64,
+    pub status: &'static str,
+    pub expected: Option<&'a str>,
+    pub error: Option<&'a str>,
+}
+
+impl<'a> From<&'a Outcome> for Record<'a> {
+    fn from(outcome: &'a Outcome) -> Self {
+        let (status, expected, error) = match &outcome.status {
+            Status::Solved { verdict, .. } => match verdict {
+                Verdict::Correct => ("correct", None, None),
+                Verdict::Wrong { expected } => ("wrong", Some(expected.as_str()), None),
+                Verdict::Unknown => ("unknown", None, None),
+            },
+            Status::Failed(error) => ("failed", None, Some(error.as_str())),
+        };
+
+        Record {
+            day: outcome.day,
+            part: outcome.part.map(|part| match part {
+                Part::One => 1,
+                Part::Two => 2,
+            }),
+            input: &outcome.input,
+            answer: outcome.answer(),
+            duration_ms: outcome.duration.as_secs_f64() * 1000.0,
+            status,
+            expected,
+            error,
+        }
+    }
+}
+
+/// The outcomes as a pretty-printed JSON array of records.
+pub fn json(outcomes: &[Outcome]) -> Result<String> {
+    let records: Vec<Record> = outcomes.iter().map(Record::from).collect();
+    Ok(serde_json::to_string_pretty(&records)?)
+}
+
+/// The outcomes as CSV with a header row, one record per line.
+pub fn csv(outcomes: &[Outcome]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for outcome in outcomes {
+        writer.serialize(Record::from(outcome))?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn outcomes() -> Vec<Outcome> {
+        vec![
+            Outcome {
+                day: 2,
+                part: Some(Part::Two),
+                input: "example".to_owned(),
+                status: Status::Solved {
+                    answer: "2286".to_owned(),
+                    verdict: Verdict::Wrong {
+                        expected: "1".to_owned(),
+                    },
+                },
+                duration: Duration::from_micros(1500),
+            },
+            Outcome {
+                day: 12,
+                part: None,
+                input: "input".to_owned(),
+                status: Status::Failed("missing, \"day12.txt\"".to_owned()),
+                duration: Duration::ZERO,
+            },
+        ]
+    }
+
+    #[test]
+    fn writes_json() {
+        let value: serde_json::Value = serde_json::from_str(&json(&outcomes()).unwrap()).unwrap();
+        assert_eq!(
+            value[0],
+            serde_json::json!({
+                "day": 2,
+                "part": 2,
+                "input": "example",
+                "answer": "2286",
+                "duration_ms": 1.5,
+                "status": "wrong",
+                "expected": "1",
+                "error": null,
+            })
+        );
+        assert_eq!(value[1]["status"], "failed");
+        assert_eq!(value[1]["part"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn writes_csv() {
+        assert_eq!(
+            csv(&outcomes()).unwrap(),
+            "day,part,input,answer,duration_ms,status,expected,error\n\
+             2,2,example,2286,1.5,wrong,1,\n\
+             12,,input,,0.0,failed,,\"missing, \"\"day12.txt\"\"\"\n"
+        );
+    }
+}
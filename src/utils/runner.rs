@@ -0,0 +1,353 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::anyhow;
+
+use crate::{
+    answers::{input_key, Answers, Verdict},
+    input::InputSource,
+    solver::{is_not_implemented, Day, Part},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved { answer: String, verdict: Verdict },
+    Failed(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved { verdict, .. } => write!(f, "{verdict}"),
+            Status::Failed(error) => write!(f, "failed: {error}"),
+        }
+    }
+}
+
+/// The result of solving one part of a day for one input. `part` is `None`
+/// when the input could not be read, so neither part was attempted.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    pub day: u32,
+    pub part: Option<Part>,
+    pub input: String,
+    pub status: Status,
+    pub duration: Duration,
+}
+
+impl Outcome {
+    pub fn answer(&self) -> Option<&str> {
+        match &self.status {
+            Status::Solved { answer, .. } => Some(answer),
+            Status::Failed(_) => None,
+        }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status, Status::Failed(_))
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self.status,
+            Status::Solved {
+                verdict: Verdict::Wrong { .. },
+                ..
+            }
+        )
+    }
+}
+
+/// The inputs of a day to solve: `dayN.txt`, or every `dayN_example*.txt`
+/// in `dir` when `examples` is set.
+pub fn inputs(dir: &Path, day: u32, examples: bool) -> Vec<InputSource> {
+    if !examples {
+        return vec![InputSource::named(format!("day{day}.txt"))];
+    }
+
+    let prefix = format!("day{day}_example");
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .filter(|name| {
+            name.strip_prefix(&prefix)
+                .and_then(|rest| rest.strip_suffix(".txt"))
+                .is_some_and(|rest| {
+                    rest.is_empty()
+                        || rest
+                            .strip_prefix('_')
+                            .is_some_and(|n| n.chars().all(|c| c.is_ascii_digit()))
+                })
+        })
+        .collect();
+    names.sort();
+
+    names.into_iter().map(InputSource::named).collect()
+}
+
+/// Solves every implemented part of `day` for one input. Parts that are not
+/// implemented are left out, and so are parts of an example that has answers
+/// for the other part only, since examples are often written for one part.
+pub fn solve_input(
+    day: &Day,
+    dir: &Path,
+    source: &InputSource,
+    answers: &Answers,
+    timeout: Option<Duration>,
+) -> Vec<Outcome> {
+    let input = match source.read_in(dir) {
+        Ok(input) => input,
+        Err(e) => {
+            return vec![Outcome {
+                day: day.day,
+                part: None,
+                input: input_key(day.day, source).unwrap_or_else(|| source.to_string()),
+                status: Status::Failed(format!("{e:#}")),
+                duration: Duration::ZERO,
+            }]
+        }
+    };
+
+    let key = input_key(day.day, source);
+    let example_for_other_part = |part| {
+        key.as_ref().is_some_and(|key| {
+            key.starts_with("example")
+                && answers.has_input(day.day, key)
+                && answers.expected(day.day, key, part).is_none()
+        })
+    };
+
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| !example_for_other_part(*part))
+        .filter_map(|part| solve_part(day, &input, source, part, answers, timeout))
+        .collect()
+}
+
+/// Runs `solve` on its own thread and gives up after `timeout`. The thread
+/// cannot be stopped, so it keeps running until the program exits.
+fn solve_with_timeout(
+    solve: fn(&str, Part) -> anyhow::Result<String>,
+    input: &str,
+    part: Part,
+    timeout: Duration,
+) -> anyhow::Result<String> {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    thread::spawn(move || {
+        let _ = sender.send(solve(&input, part));
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => Err(anyhow!("timed out after {timeout:.0?}")),
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(anyhow!("panicked")),
+    }
+}
+
+/// Solves one part of `day` for an input that was already read from
+/// `source`, or returns `None` if the part is not implemented. With a
+/// `timeout`, parts that take longer are reported as failed.
+pub fn solve_part(
+    day: &Day,
+    input: &str,
+    source: &InputSource,
+    part: Part,
+    answers: &Answers,
+    timeout: Option<Duration>,
+) -> Option<Outcome> {
+    let key = input_key(day.day, source);
+
+    let start = Instant::now();
+    let result = match timeout {
+        Some(timeout) => solve_with_timeout(day.solve, input, part, timeout),
+        None => (day.solve)(input, part),
+    };
+    let duration = start.elapsed();
+
+    let status = match result {
+        Ok(answer) => {
+            let verdict = match &key {
+                Some(key) => answers.check(day.day, key, part, &answer),
+                None => Verdict::Unknown,
+            };
+            Status::Solved { answer, verdict }
+        }
+        Err(e) if is_not_implemented(&e) => return None,
+        Err(e) => Status::Failed(format!("{e:#}")),
+    };
+
+    Some(Outcome {
+        day: day.day,
+        part: Some(part),
+        input: key.unwrap_or_else(|| source.to_string()),
+        status,
+        duration,
+    })
+}
+
+/// Solves all inputs of every given day, optionally one thread per day. The
+/// outcomes are in the order of `days` either way.
+pub fn solve_all(
+    days: &[&Day],
+    dir: &Path,
+    examples: bool,
+    answers: &Answers,
+    parallel: bool,
+    timeout: Option<Duration>,
+) -> Vec<Outcome> {
+    let solve_day = |day: &Day| -> Vec<Outcome> {
+        inputs(dir, day.day, examples)
+            .iter()
+            .flat_map(|source| solve_input(day, dir, source, answers, timeout))
+            .collect()
+    };
+
+    if !parallel {
+        return days.iter().flat_map(|day| solve_day(day)).collect();
+    }
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = days
+            .iter()
+            .map(|day| scope.spawn(|| solve_day(day)))
+            .collect();
+
+        handles
+            .into_iter()
+            .zip(days)
+            .flat_map(|(handle, day)| {
+                handle.join().unwrap_or_else(|_| {
+                    vec![Outcome {
+                        day: day.day,
+                        part: None,
+                        input: String::new(),
+                        status: Status::Failed("panicked".to_owned()),
+                        duration: Duration::ZERO,
+                    }]
+                })
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::days;
+
+    fn input_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+    }
+
+    #[test]
+    fn finds_example_inputs() {
+        let names: Vec<String> = inputs(&input_dir(), 1, true)
+            .iter()
+            .map(|source| source.to_string())
+            .collect();
+        assert_eq!(names, vec!["day1_example_1.txt", "day1_example_2.txt"]);
+
+        assert_eq!(
+            inputs(&input_dir(), 1, false),
+            vec![InputSource::named("day1.txt")]
+        );
+    }
+
+    #[test]
+    fn verifies_answers() {
+        let answers = Answers::parse("[day2.example]\npart1 = 8\npart2 = 1\n").unwrap();
+        let day = days::get(2).unwrap();
+
+        let outcomes = solve_all(&[day], &input_dir(), true, &answers, true, None);
+        let statuses: Vec<_> = outcomes
+            .iter()
+            .map(|o| (o.part, o.input.as_str(), o.is_wrong()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (Some(Part::One), "example", false),
+                (Some(Part::Two), "example", true)
+            ]
+        );
+        assert_eq!(outcomes[1].answer(), Some("2286"));
+    }
+
+    #[test]
+    fn skips_parts_an_example_is_not_for() {
+        let answers = Answers::parse("[day1.example_2]\npart2 = 281\n").unwrap();
+        let day = days::get(1).unwrap();
+
+        let parts: Vec<_> = solve_input(
+            day,
+            &input_dir(),
+            &InputSource::named("day1_example_2.txt"),
+            &answers,
+            None,
+        )
+        .iter()
+        .map(|o| o.part)
+        .collect();
+        assert_eq!(parts, vec![Some(Part::Two)]);
+
+        let outcomes = solve_input(
+            day,
+            &input_dir(),
+            &InputSource::named("day1_example_2.txt"),
+            &Answers::default(),
+            None,
+        );
+        assert_eq!(outcomes.len(), 2);
+    }
+
+    fn slow(_input: &str, _part: Part) -> anyhow::Result<String> {
+        thread::sleep(Duration::from_secs(5));
+        Ok("done".to_owned())
+    }
+
+    #[test]
+    fn gives_up_after_the_timeout() {
+        let day = Day {
+            day: 2,
+            solve: slow,
+            bench: days::get(2).unwrap().bench,
+        };
+        let outcome = solve_part(
+            &day,
+            "",
+            &InputSource::text(""),
+            Part::One,
+            &Answers::default(),
+            Some(Duration::from_millis(10)),
+        )
+        .unwrap();
+        assert!(outcome.is_failed());
+        assert!(outcome.duration < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn reports_unreadable_inputs() {
+        let day = days::get(2).unwrap();
+        let outcomes = solve_input(
+            day,
+            &input_dir(),
+            &InputSource::named("missing.txt"),
+            &Answers::default(),
+            None,
+        );
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].part, None);
+        assert!(outcomes[0].is_failed());
+    }
+}
@@ -0,0 +1,367 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+const TEMPLATE: &str = r#"use anyhow::Result;
+use log::trace;
+
+use crate::solver::{not_implemented, Solver};
+
+pub struct Day{day};
+
+impl Solver for Day{day} {
+    const DAY: u32 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+        trace!("{:?}", lines);
+        Ok(lines)
+    }
+
+    fn part_one(_input: &Self::Input) -> Result<Self::Answer1> {
+        not_implemented()
+    }
+
+    fn part_two(_input: &Self::Input) -> Result<Self::Answer2> {
+        not_implemented()
+    }
+}
+"#;
+
+const BIN_TEMPLATE: &str = r#"use anyhow::Result;
+use utils::{days::day{day}::Day{day}, solver::run};
+
+fn main() -> Result<()> {
+    run::<Day{day}>("day{day}.txt")
+}
+"#;
+
+const TEST_TEMPLATE: &str = r#"#[test]
+fn day{day}() {
+    check::<Day{day}>("day{day}_example.txt", None, None);
+}
+"#;
+
+fn fill(template: &str, day: u32) -> String {
+    template.replace("{day}", &day.to_string())
+}
+
+/// rustfmt's default line width, which the generated code keeps to.
+const MAX_WIDTH: usize = 100;
+
+fn edited(path: &Path, edit: impl FnOnce(&str) -> Result<String>) -> Result<String> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Could not read {}", path.display()))?;
+    edit(&text).with_context(|| format!("Could not update {}", path.display()))
+}
+
+fn create(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).with_context(|| format!("Could not write {}", path.display()))
+}
+
+/// The day number of an item like `day12::Day12` or a line like
+/// `    Day::of::<day12::Day12>(),`.
+fn day_of(item: &str) -> Option<u32> {
+    let rest = &item[item.find("day")? + 3..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..end].parse().ok()
+}
+
+/// Adds `pub mod dayN;` and the registry entry for day `day` to the text
+/// of `days.rs`, keeping both lists in the order rustfmt and the registry
+/// expect.
+pub fn add_to_days(text: &str, day: u32) -> Result<String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!("    Day::of::<day{day}::Day{day}>(),");
+
+    let mut lines: Vec<String> = text.lines().map(|l| l.to_owned()).collect();
+    if lines.contains(&module) {
+        bail!("Day {day} is already registered");
+    }
+
+    let modules = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod day"))
+        .ok_or_else(|| anyhow!("No day modules found"))?;
+    // rustfmt sorts by module name, so `day10` comes before `day2`.
+    let name = |line: &str| {
+        line.trim_start_matches("pub mod ")
+            .trim_end_matches(';')
+            .to_owned()
+    };
+    let module_at = lines[modules..]
+        .iter()
+        .position(|l| !l.starts_with("pub mod day") || name(l) > name(&module))
+        .map_or(lines.len(), |i| modules + i);
+    lines.insert(module_at, module);
+
+    let days = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or_else(|| anyhow!("No DAYS registry found"))?;
+    let entry_at = lines[days + 1..]
+        .iter()
+        .position(|l| day_of(l).is_none_or(|d| d > day))
+        .map(|i| days + 1 + i)
+        .ok_or_else(|| anyhow!("DAYS registry is not terminated"))?;
+    lines.insert(entry_at, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Lays out `days::{...}` at `indent` the way rustfmt does: on one line if
+/// it fits, otherwise as a block with the items filled into lines.
+fn format_days_import(items: &[String], indent: &str) -> String {
+    let single = format!("days::{{{}}}", items.join(", "));
+    if indent.len() + single.len() + ",".len() <= MAX_WIDTH {
+        return single;
+    }
+
+    let inner = format!("{indent}    ");
+    let mut lines: Vec<String> = vec![];
+    let mut line = String::new();
+    for item in items {
+        if !line.is_empty() && inner.len() + line.len() + " ".len() + item.len() + 1 > MAX_WIDTH {
+            lines.push(format!("{inner}{line}"));
+            line.clear();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(item);
+        line.push(',');
+    }
+    lines.push(format!("{inner}{line}"));
+
+    format!("days::{{\n{}\n{indent}}}", lines.join("\n"))
+}
+
+/// Adds the import of `DayN` and a skeleton example test to the text of
+/// `tests/examples.rs`, formatted as rustfmt would.
+pub fn add_example_test(text: &str, day: u32) -> Result<String> {
+    let import = text
+        .find("days::{")
+        .ok_or_else(|| anyhow!("No import of the days found"))?;
+    let start = import + "days::{".len();
+    let end = start
+        + text[start..]
+            .find('}')
+            .ok_or_else(|| anyhow!("Unterminated import of the days"))?;
+    let line_start = text[..import].rfind('\n').map_or(0, |i| i + 1);
+    let indent = &text[line_start..import];
+
+    let mut items: Vec<String> = text[start..end]
+        .split(',')
+        .map(|item| item.trim().to_owned())
+        .filter(|item| !item.is_empty())
+        .collect();
+    items.push(format!("day{day}::Day{day}"));
+    items.sort_by(|a, b| a.split("::").cmp(b.split("::")));
+
+    let mut text = format!(
+        "{}{}{}",
+        &text[..import],
+        format_days_import(&items, indent),
+        &text[end + 1..]
+    );
+
+    let test = fill(TEST_TEMPLATE, day);
+    let next_test = text
+        .match_indices("#[test]\nfn day")
+        .find(|(i, _)| day_of(&text[i + "#[test]\n".len()..]).is_some_and(|d| d > day))
+        .map(|(i, _)| i);
+    match next_test {
+        Some(i) => text.insert_str(i, &format!("{test}\n")),
+        None => {
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push('\n');
+            text.push_str(&test);
+        }
+    }
+
+    Ok(text)
+}
+
+/// Creates the files for a new day below the project root `root` and wires
+/// it into the registry and the example tests. Returns the paths of the new
+/// and changed files. The new files are written first, so a failure never
+/// leaves the registry pointing at a module that does not exist.
+pub fn scaffold(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        bail!("There is no day {day} in the calendar");
+    }
+
+    let module = root.join(format!("src/utils/days/day{day}.rs"));
+    let binary = root.join(format!("src/bin/day{day}.rs"));
+    for path in [&module, &binary] {
+        if path.exists() {
+            bail!("{} already exists", path.display());
+        }
+    }
+
+    let days = root.join("src/utils/days.rs");
+    let examples = root.join("tests/examples.rs");
+    let days_text = edited(&days, |text| add_to_days(text, day))?;
+    let examples_text = edited(&examples, |text| add_example_test(text, day))?;
+
+    create(&module, &fill(TEMPLATE, day))?;
+    create(&binary, &fill(BIN_TEMPLATE, day))?;
+    create(&days, &days_text)?;
+    create(&examples, &examples_text)?;
+
+    let mut paths = vec![days, examples, module, binary];
+
+    let inputs = root.join("input");
+    for name in [format!("day{day}.txt"), format!("day{day}_example.txt")] {
+        let path = inputs.join(name);
+        if !path.exists() {
+            create(&path, "")?;
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const DAYS: &str = "pub mod day1;
+pub mod day10;
+pub mod day2;
+
+use crate::solver::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::of::<day1::Day1>(),
+    Day::of::<day2::Day2>(),
+    Day::of::<day10::Day10>(),
+];
+";
+
+    const EXAMPLES: &str = "use utils::{
+    days::{day1::Day1, day10::Day10, day2::Day2},
+    solver::Solver,
+};
+
+#[test]
+fn day1() {
+    check::<Day1>(\"day1_example.txt\", None, None);
+}
+
+#[test]
+fn day10() {
+    check::<Day10>(\"day10_example.txt\", None, None);
+}
+";
+
+    #[test]
+    fn registers_the_day_in_order() {
+        let text = add_to_days(DAYS, 3).unwrap();
+        assert!(text.starts_with("pub mod day1;\npub mod day10;\npub mod day2;\npub mod day3;\n"));
+        assert!(text.contains(
+            "    Day::of::<day2::Day2>(),\n    Day::of::<day3::Day3>(),\n    Day::of::<day10::Day10>(),\n];"
+        ));
+
+        let text = add_to_days(DAYS, 11).unwrap();
+        assert!(text.contains("pub mod day10;\npub mod day11;\npub mod day2;"));
+        assert!(text.contains("    Day::of::<day11::Day11>(),\n];"));
+
+        assert!(add_to_days(DAYS, 2).is_err());
+    }
+
+    #[test]
+    fn adds_an_example_test() {
+        let text = add_example_test(EXAMPLES, 3).unwrap();
+        assert!(text.contains("days::{day1::Day1, day10::Day10, day2::Day2, day3::Day3},"));
+        assert!(text.contains(
+            "fn day3() {\n    check::<Day3>(\"day3_example.txt\", None, None);\n}\n\n#[test]\nfn day10()"
+        ));
+
+        let text = add_example_test(EXAMPLES, 12).unwrap();
+        assert!(text.ends_with("}\n\n#[test]\nfn day12() {\n    check::<Day12>(\"day12_example.txt\", None, None);\n}\n"));
+    }
+
+    #[test]
+    fn leaves_the_tree_alone_when_an_edit_fails() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-broken-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["src/utils/days", "src/bin", "tests", "input"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/utils/days.rs"), DAYS).unwrap();
+        fs::write(root.join("tests/examples.rs"), "// no imports").unwrap();
+
+        assert!(scaffold(&root, 3).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/utils/days.rs")).unwrap(),
+            DAYS
+        );
+        assert!(!root.join("src/utils/days/day3.rs").exists());
+        assert!(!root.join("src/bin/day3.rs").exists());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn formats_a_long_import_like_rustfmt() {
+        let text = add_example_test(include_str!("../../tests/examples.rs"), 11).unwrap();
+        assert!(text.contains(
+            "use utils::{
+    days::{
+        day1::Day1, day10::Day10, day11::Day11, day12::Day12, day14::Day14, day17::Day17,
+        day2::Day2, day3::Day3, day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8,
+        day9::Day9,
+    },
+    input::InputSource,"
+        ));
+    }
+
+    #[test]
+    fn creates_the_files() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["src/utils/days", "src/bin", "tests", "input"] {
+            fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        fs::write(root.join("src/utils/days.rs"), DAYS).unwrap();
+        fs::write(root.join("tests/examples.rs"), EXAMPLES).unwrap();
+        fs::write(root.join("input/day3.txt"), "kept").unwrap();
+
+        let paths = scaffold(&root, 3).unwrap();
+        assert_eq!(paths.len(), 5);
+        assert!(fs::read_to_string(root.join("src/utils/days/day3.rs"))
+            .unwrap()
+            .contains("impl Solver for Day3 {\n    const DAY: u32 = 3;"));
+        assert!(fs::read_to_string(root.join("src/bin/day3.rs"))
+            .unwrap()
+            .contains("run::<Day3>(\"day3.txt\")"));
+        assert_eq!(
+            fs::read_to_string(root.join("input/day3.txt")).unwrap(),
+            "kept"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("input/day3_example.txt")).unwrap(),
+            ""
+        );
+
+        assert!(scaffold(&root, 3).is_err());
+        assert!(scaffold(&root, 26).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
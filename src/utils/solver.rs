@@ -0,0 +1,123 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use anyhow::{bail, Error, Result};
+
+use crate::{
+    bench::{bench, Benchmark},
+    input::InputSource,
+    logging,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => bail!("Unknown part {s}, expected 1 or 2"),
+        }
+    }
+}
+
+/// Returned by parts that have not been solved yet.
+#[derive(Debug)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl std::error::Error for NotImplemented {}
+
+pub fn not_implemented<T>() -> Result<T> {
+    Err(Error::new(NotImplemented))
+}
+
+pub fn is_not_implemented(error: &Error) -> bool {
+    error.downcast_ref::<NotImplemented>().is_some()
+}
+
+/// A puzzle solution. The input is parsed once and then handed to both parts.
+pub trait Solver {
+    const DAY: u32;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_one(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part_two(input: &Self::Input) -> Result<Self::Answer2>;
+}
+
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<String> {
+    let input = S::parse(input)?;
+    match part {
+        Part::One => S::part_one(&input).map(|answer| answer.to_string()),
+        Part::Two => S::part_two(&input).map(|answer| answer.to_string()),
+    }
+}
+
+/// Type-erased entry for a day, so solutions can be looked up by number.
+pub struct Day {
+    pub day: u32,
+    pub solve: fn(&str, Part) -> Result<String>,
+    pub bench: fn(&str, usize) -> Result<Benchmark>,
+}
+
+impl Day {
+    pub const fn of<S: Solver>() -> Self {
+        Day {
+            day: S::DAY,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+/// Solves both parts for the named input file and prints the answers.
+pub fn run<S: Solver>(name: &str) -> Result<()> {
+    run_source::<S>(&InputSource::named(name))
+}
+
+pub fn run_source<S: Solver>(source: &InputSource) -> Result<()> {
+    logging::init(0, None);
+
+    let input = source.read()?;
+    let input = S::parse(&input)?;
+
+    print_answer(Part::One, S::part_one(&input))?;
+    print_answer(Part::Two, S::part_two(&input))?;
+
+    Ok(())
+}
+
+fn print_answer<T: Display>(part: Part, answer: Result<T>) -> Result<()> {
+    match answer {
+        Ok(answer) => println!("Part {part}: {answer}"),
+        Err(e) if is_not_implemented(&e) => println!("Part {part}: {e}"),
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
@@ -1,15 +1,88 @@
-use std::{env, fs};
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod logging;
+pub mod parse;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
 use anyhow::Result;
+use input::InputSource;
+
+/// Environment variable that overrides the directory inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory puzzle inputs are read from: `$AOC_INPUT_DIR` if set, otherwise
+/// `input/` below the current directory, falling back to the one in this
+/// repository so binaries work from anywhere.
+pub fn input_dir() -> PathBuf {
+    resolve_input_dir(env::var_os(INPUT_DIR_VAR), env::current_dir().ok())
+}
+
+fn resolve_input_dir(var: Option<OsString>, cwd: Option<PathBuf>) -> PathBuf {
+    if let Some(dir) = var {
+        return PathBuf::from(dir);
+    }
+
+    match cwd.map(|cwd| cwd.join("input")) {
+        Some(local) if local.is_dir() => local,
+        _ => Path::new(env!("CARGO_MANIFEST_DIR")).join("input"),
+    }
+}
 
 pub fn get_input(name: &str) -> Result<String> {
-    let cwd = env::current_dir()?;
-    let input_path = cwd.join("input/").join(name);
+    InputSource::named(name).read()
+}
 
-    Ok(fs::read_to_string(input_path)?)
+pub fn get_input_from(dir: &Path, name: &str) -> Result<String> {
+    InputSource::named(name).read_in(dir)
 }
 
 pub fn get_lines(name: &str) -> Result<Vec<String>> {
-    let input = get_input(name)?;
-    let lines = input.lines();
-    Ok(lines.map(|s| s.to_string()).collect())
+    InputSource::named(name).lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, process};
+
+    use super::*;
+
+    #[test]
+    fn variable_overrides_the_input_dir() {
+        assert_eq!(
+            resolve_input_dir(Some("/tmp/aoc".into()), env::current_dir().ok()),
+            PathBuf::from("/tmp/aoc")
+        );
+    }
+
+    #[test]
+    fn prefers_input_below_the_current_dir() {
+        let cwd = env::temp_dir().join(format!("aoc-cwd-{}", process::id()));
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("input");
+
+        fs::create_dir_all(&cwd).unwrap();
+        assert_eq!(resolve_input_dir(None, Some(cwd.clone())), manifest);
+
+        fs::create_dir_all(cwd.join("input")).unwrap();
+        assert_eq!(
+            resolve_input_dir(None, Some(cwd.clone())),
+            cwd.join("input")
+        );
+
+        assert_eq!(resolve_input_dir(None, None), manifest);
+        fs::remove_dir_all(cwd).unwrap();
+    }
 }
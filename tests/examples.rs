@@ -0,0 +1,100 @@
+//! Runs every day against its example input and checks the answers published
+//! in the puzzle text. Parts that are not implemented yet are skipped.
+
+use anyhow::Result;
+use utils::{
+    days::{
+        day1::Day1, day10::Day10, day12::Day12, day14::Day14, day17::Day17, day2::Day2, day3::Day3,
+        day4::Day4, day5::Day5, day6::Day6, day7::Day7, day8::Day8, day9::Day9,
+    },
+    input::InputSource,
+    solver::{is_not_implemented, Part, Solver},
+};
+
+fn check_answer<T: ToString>(name: &str, part: Part, answer: Result<T>, expected: Option<&str>) {
+    let Some(expected) = expected else {
+        return;
+    };
+
+    match answer {
+        Ok(answer) => assert_eq!(answer.to_string(), expected, "{name}, part {part}"),
+        Err(e) if is_not_implemented(&e) => eprintln!("{name}, part {part}: skipped, {e}"),
+        Err(e) => panic!("{name}, part {part} failed: {e:?}"),
+    }
+}
+
+fn check<S: Solver>(name: &str, part_one: Option<&str>, part_two: Option<&str>) {
+    let input = InputSource::named(name).read().expect("example input");
+    let input = S::parse(&input).expect("example input parses");
+
+    check_answer(name, Part::One, S::part_one(&input), part_one);
+    check_answer(name, Part::Two, S::part_two(&input), part_two);
+}
+
+#[test]
+fn day1() {
+    check::<Day1>("day1_example_1.txt", Some("142"), Some("142"));
+    check::<Day1>("day1_example_2.txt", None, Some("281"));
+}
+
+#[test]
+fn day2() {
+    check::<Day2>("day2_example.txt", Some("8"), Some("2286"));
+}
+
+#[test]
+fn day3() {
+    check::<Day3>("day3_example.txt", Some("4361"), Some("467835"));
+}
+
+#[test]
+fn day4() {
+    check::<Day4>("day4_example.txt", Some("13"), Some("30"));
+}
+
+#[test]
+fn day5() {
+    check::<Day5>("day5_example.txt", Some("35"), Some("46"));
+}
+
+#[test]
+fn day6() {
+    check::<Day6>("day6_example.txt", Some("288"), Some("71503"));
+}
+
+#[test]
+fn day7() {
+    check::<Day7>("day7_example.txt", Some("6440"), Some("5905"));
+}
+
+#[test]
+fn day8() {
+    // The example only has ghost starting points, so it is for part 2.
+    check::<Day8>("day8_example.txt", None, Some("6"));
+}
+
+#[test]
+fn day9() {
+    check::<Day9>("day9_example.txt", Some("114"), Some("2"));
+}
+
+#[test]
+fn day10() {
+    check::<Day10>("day10_example_1.txt", Some("4"), Some("1"));
+    check::<Day10>("day10_example_2.txt", Some("8"), None);
+}
+
+#[test]
+fn day12() {
+    check::<Day12>("day12_example.txt", Some("21"), Some("525152"));
+}
+
+#[test]
+fn day14() {
+    check::<Day14>("day14_example.txt", Some("136"), Some("64"));
+}
+
+#[test]
+fn day17() {
+    check::<Day17>("day17_example.txt", Some("102"), Some("94"));
+}